@@ -0,0 +1 @@
+CREATE INDEX items_preparation_time_idx on items (preparation_time);
@@ -0,0 +1,3 @@
+ALTER TABLE items ADD COLUMN station VARCHAR NOT NULL DEFAULT 'grill';
+
+CREATE INDEX items_station_idx on items (station, preparation_time);
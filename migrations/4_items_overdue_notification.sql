@@ -0,0 +1,3 @@
+ALTER TABLE items ADD COLUMN overdue_notified_at TIMESTAMPTZ;
+
+CREATE INDEX items_overdue_idx on items (preparation_time) WHERE overdue_notified_at IS NULL;
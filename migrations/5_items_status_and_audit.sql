@@ -0,0 +1,16 @@
+ALTER TABLE items ADD COLUMN status VARCHAR NOT NULL DEFAULT 'pending';
+
+CREATE INDEX items_pending_idx on items (preparation_time) WHERE status = 'pending';
+
+CREATE TABLE item_audit
+(
+    id          BIGSERIAL PRIMARY KEY,
+    item_id     UUID NOT NULL,
+    table_id    BIGINT NOT NULL,
+    from_status VARCHAR NOT NULL,
+    to_status   VARCHAR NOT NULL,
+    actor       VARCHAR NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX item_audit_item_idx on item_audit (item_id);
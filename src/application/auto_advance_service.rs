@@ -0,0 +1,48 @@
+use crate::application::item_service::{ApplicationError, ItemModel};
+use crate::domain::audit::SYSTEM_ACTOR;
+use crate::domain::clock::Clock;
+use crate::domain::repository::ItemRepository;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+use tracing::info;
+
+#[automock]
+#[async_trait]
+pub trait AutoAdvanceService {
+    async fn advance_ready_items(&self) -> Result<Vec<ItemModel>, ApplicationError>;
+}
+
+pub struct AutoAdvanceServiceImpl {
+    repository: Arc<dyn ItemRepository + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl AutoAdvanceServiceImpl {
+    pub fn new(
+        repository: Arc<dyn ItemRepository + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+}
+
+#[async_trait]
+impl AutoAdvanceService for AutoAdvanceServiceImpl {
+    async fn advance_ready_items(&self) -> Result<Vec<ItemModel>, ApplicationError> {
+        let now = self.clock.now();
+        let models = self
+            .repository
+            .advance_ready_items(&now, SYSTEM_ACTOR)
+            .await?
+            .into_iter()
+            .map(ItemModel::from)
+            .collect::<Vec<_>>();
+
+        if !models.is_empty() {
+            info!("Marked {} items as ready", models.len());
+        }
+
+        Ok(models)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::item::ItemValidationError;
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+// Codes are part of the API contract, so existing ones must not be renamed. `as_str` is the only
+// place they are spelled out, serialization goes through it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorCode {
+    EmptyName,
+    NegativeTableId,
+    NotesTooLong,
+    EmptyItems,
+    InvalidLimit,
+    EmptyTimeWindow,
+    NothingToUpdate,
+    ValidationFailed,
+    NotFound,
+    Conflict,
+    InternalError,
+    ServiceUnavailable,
+    MalformedJson,
+    InvalidBody,
+    MissingContentType,
+    InvalidPath,
+    InvalidQuery,
+    InvalidCursor,
+    UnknownField,
+    UnknownParameter,
+    ConflictingRepresentation,
+    EmptyFieldSelection,
+    MalformedRequestId,
+    TooManyItems,
+    PayloadTooLarge,
+    RequestTimeout,
+    Overloaded,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::EmptyName => "empty_name",
+            ErrorCode::NegativeTableId => "negative_table_id",
+            ErrorCode::NotesTooLong => "notes_too_long",
+            ErrorCode::EmptyItems => "empty_items",
+            ErrorCode::InvalidLimit => "invalid_limit",
+            ErrorCode::EmptyTimeWindow => "empty_time_window",
+            ErrorCode::NothingToUpdate => "nothing_to_update",
+            ErrorCode::ValidationFailed => "validation_failed",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::InternalError => "internal_error",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::MalformedJson => "malformed_json",
+            ErrorCode::InvalidBody => "invalid_body",
+            ErrorCode::MissingContentType => "missing_content_type",
+            ErrorCode::InvalidPath => "invalid_path",
+            ErrorCode::InvalidQuery => "invalid_query",
+            ErrorCode::InvalidCursor => "invalid_cursor",
+            ErrorCode::UnknownField => "unknown_field",
+            ErrorCode::UnknownParameter => "unknown_parameter",
+            ErrorCode::ConflictingRepresentation => "conflicting_representation",
+            ErrorCode::EmptyFieldSelection => "empty_field_selection",
+            ErrorCode::MalformedRequestId => "malformed_request_id",
+            ErrorCode::TooManyItems => "too_many_items",
+            ErrorCode::PayloadTooLarge => "payload_too_large",
+            ErrorCode::RequestTimeout => "request_timeout",
+            ErrorCode::Overloaded => "overloaded",
+        }
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&ItemValidationError> for ErrorCode {
+    fn from(error: &ItemValidationError) -> Self {
+        match error {
+            ItemValidationError::EmptyName => ErrorCode::EmptyName,
+            ItemValidationError::NegativeTableId => ErrorCode::NegativeTableId,
+            ItemValidationError::NotesTooLong => ErrorCode::NotesTooLong,
+        }
+    }
+}
+
+// A single validation failure. The field is a path into the request, e.g. `items[1].name`, and is
+// left out when the failure is not caused by one particular field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violation {
+    pub code: ErrorCode,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            field: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_field(self, field: impl Into<String>) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+}
+
+impl From<ItemValidationError> for Violation {
+    fn from(error: ItemValidationError) -> Self {
+        Violation::new(ErrorCode::from(&error), error.to_string()).with_field(error.field())
+    }
+}
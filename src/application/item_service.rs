@@ -1,15 +1,25 @@
-use crate::domain::item::{Item, ItemValidationError};
+use crate::application::error_code::{ErrorCode, Violation};
+use crate::application::payload_logging::{PayloadLogging, Redact};
+use crate::domain::item::{Item, ItemStatus, ItemValidationError};
 use crate::domain::item_factory::ItemFactory;
+use crate::domain::item_query::{ItemCursor, ItemPage, ItemQuery, MAX_PAGE_SIZE};
+use crate::domain::metrics::ItemMetrics;
+use crate::domain::preparation_estimator::{KitchenCapacity, PreparationTimeEstimator};
 use crate::domain::repository::{ItemRepository, RepositoryError};
-use chrono::{DateTime, Utc};
+use crate::domain::station::Station;
+use chrono::{DateTime, SubsecRound, TimeDelta, Utc};
 
 use async_trait::async_trait;
 use mockall::automock;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
-use tracing::info;
+use tokio::sync::Mutex;
+use tracing::{info, instrument, Span};
 use uuid::Uuid;
 
+const PREPARATION_STARTED: &str = "Item preparation has already started.";
+
 #[automock]
 #[async_trait]
 pub trait ItemService {
@@ -18,21 +28,51 @@ pub trait ItemService {
         table_id: i64,
         command: CreateItemsCommand,
     ) -> Result<Vec<ItemModel>, ApplicationError>;
+    async fn create_batch(
+        &self,
+        command: BatchCreateItemsCommand,
+    ) -> Result<BatchModel, ApplicationError>;
     async fn get_item(&self, table_id: i64, item_id: Uuid) -> Result<ItemModel, ApplicationError>;
-    async fn get_items(&self, table_id: i64) -> Result<Vec<ItemModel>, ApplicationError>;
+    async fn get_items(
+        &self,
+        table_id: i64,
+        query: ItemQuery,
+    ) -> Result<ItemModelPage, ApplicationError>;
+    async fn get_station_items(&self, station: Station)
+        -> Result<Vec<ItemModel>, ApplicationError>;
+    async fn update_item(
+        &self,
+        table_id: i64,
+        item_id: Uuid,
+        command: UpdateItemCommand,
+    ) -> Result<ItemModel, ApplicationError>;
     async fn delete_item(&self, table_id: i64, item_id: Uuid) -> Result<(), ApplicationError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ApplicationError {
     InternalError,
-    ValidationError(String),
+    Unavailable,
+    ValidationError(Vec<Violation>),
     ResourceNotFound,
+    Conflict(String),
+}
+
+impl ApplicationError {
+    pub fn invalid(violation: Violation) -> Self {
+        ApplicationError::ValidationError(vec![violation])
+    }
 }
 
 pub struct ItemServiceImpl {
     repository: Arc<dyn ItemRepository + Send + Sync>,
     factory: Arc<dyn ItemFactory + Send + Sync>,
+    estimator: Option<Arc<dyn PreparationTimeEstimator + Send + Sync>>,
+    capacities: HashMap<Station, KitchenCapacity>,
+    metrics: Option<Arc<dyn ItemMetrics + Send + Sync>>,
+    payload_logging: PayloadLogging,
+    max_items: Option<usize>,
+    scheduling: Mutex<()>,
 }
 
 impl ItemServiceImpl {
@@ -43,38 +83,217 @@ impl ItemServiceImpl {
         Self {
             repository,
             factory,
+            estimator: None,
+            capacities: HashMap::new(),
+            metrics: None,
+            payload_logging: PayloadLogging::default(),
+            max_items: None,
+            scheduling: Mutex::new(()),
+        }
+    }
+
+    pub fn with_estimator(
+        mut self,
+        estimator: Arc<dyn PreparationTimeEstimator + Send + Sync>,
+    ) -> Self {
+        self.estimator = Some(estimator);
+        self
+    }
+
+    pub fn with_capacities(mut self, capacities: HashMap<Station, KitchenCapacity>) -> Self {
+        self.capacities = capacities;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<dyn ItemMetrics + Send + Sync>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn with_payload_logging(mut self, payload_logging: PayloadLogging) -> Self {
+        self.payload_logging = payload_logging;
+        self
+    }
+
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    fn check_item_count(&self, count: usize) -> Result<(), ApplicationError> {
+        if count == 0 {
+            return Err(ApplicationError::invalid(
+                Violation::new(ErrorCode::EmptyItems, "Items list is empty.").with_field("items"),
+            ));
+        }
+        match self.max_items {
+            Some(max_items) if count > max_items => Err(ApplicationError::invalid(
+                Violation::new(
+                    ErrorCode::TooManyItems,
+                    format!("At most {} items can be created at once.", max_items),
+                )
+                .with_field("items"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    #[instrument(skip_all, fields(item_ids))]
+    async fn store(&self, items: Vec<Item>) -> Result<Vec<Item>, ApplicationError> {
+        // Estimates are based on the queue read before saving, so creates are serialized while an
+        // estimator is used. Otherwise concurrent ones would be scheduled into the same cook slot.
+        // This only holds within a single instance.
+        let (items, _scheduling) = match &self.estimator {
+            Some(estimator) => {
+                let scheduling = self.scheduling.lock().await;
+                (estimator.estimate(items).await?, Some(scheduling))
+            }
+            None => (items, None),
+        };
+
+        let ids = items
+            .iter()
+            .map(|item| item.id.to_string())
+            .collect::<Vec<_>>();
+        Span::current().record("item_ids", ids.join(","));
+        self.repository.save_items(&items).await?;
+
+        if let Some(metrics) = &self.metrics {
+            let mut created = BTreeMap::new();
+            for item in &items {
+                *created.entry(item.table_id).or_insert(0) += 1;
+            }
+            for (table_id, count) in created {
+                metrics.items_created(table_id, count);
+            }
         }
+
+        Ok(items)
+    }
+
+    // Only estimated preparation times are scheduled one cook duration after the kitchen picks the
+    // item up. Random ones carry no such window, so those items only count as started once due.
+    fn cook_duration(&self, station: &Station) -> TimeDelta {
+        if self.estimator.is_none() {
+            return TimeDelta::zero();
+        }
+        let capacity = self.capacities.get(station).cloned().unwrap_or_default();
+        TimeDelta::seconds(capacity.cook_duration.as_secs() as i64)
     }
 }
 
 #[async_trait]
 impl ItemService for ItemServiceImpl {
+    #[instrument(skip_all, fields(table_id = table_id))]
     async fn create_items(
         &self,
         table_id: i64,
         command: CreateItemsCommand,
     ) -> Result<Vec<ItemModel>, ApplicationError> {
-        info!("Creating items from command: {:?}", command);
+        info!(
+            "Creating items from command: {}",
+            self.payload_logging.format(&command)
+        );
 
-        if command.items.is_empty() {
-            return Err(ApplicationError::ValidationError(
-                "Items list is empty.".to_string(),
-            ));
+        self.check_item_count(command.items.len())?;
+
+        // Every item is validated, so that all failures can be reported at once. The table id
+        // comes from the path, so a failure on it is reported only once.
+        let mut items = Vec::with_capacity(command.items.len());
+        let mut violations = Vec::new();
+        for (index, model) in command.items.into_iter().enumerate() {
+            match self.factory.try_create(table_id, model.name, model.station) {
+                Ok(item) => items.push(item),
+                Err(ItemValidationError::NegativeTableId) => {
+                    let violation = Violation::from(ItemValidationError::NegativeTableId);
+                    if !violations.contains(&violation) {
+                        violations.push(violation);
+                    }
+                }
+                Err(e) => {
+                    let field = format!("items[{}].{}", index, e.field());
+                    violations.push(Violation::from(e).with_field(field));
+                }
+            }
+        }
+
+        if !violations.is_empty() {
+            return Err(ApplicationError::ValidationError(violations));
         }
 
-        let items = command
-            .items
+        let models = self
+            .store(items)
+            .await?
             .into_iter()
-            .map(|model| self.factory.try_create(table_id, model.name))
-            .collect::<Result<Vec<Item>, ItemValidationError>>()?;
+            .map(ItemModel::from)
+            .collect();
 
-        self.repository.save_items(&items).await?;
+        Ok(models)
+    }
 
-        let models = items.into_iter().map(ItemModel::from).collect();
+    #[instrument(skip_all, fields(item_count = command.items.len()))]
+    async fn create_batch(
+        &self,
+        command: BatchCreateItemsCommand,
+    ) -> Result<BatchModel, ApplicationError> {
+        info!(
+            "Creating items from batch command: {}",
+            self.payload_logging.format(&command)
+        );
 
-        Ok(models)
+        self.check_item_count(command.items.len())?;
+
+        let mut items = Vec::with_capacity(command.items.len());
+        let mut errors = Vec::new();
+        for (index, model) in command.items.into_iter().enumerate() {
+            let table_id = model.table_id;
+            match self.factory.try_create(table_id, model.name, model.station) {
+                Ok(item) => items.push(item),
+                Err(e) => errors.push(BatchErrorModel {
+                    index,
+                    table_id,
+                    code: ErrorCode::from(&e),
+                    field: e.field(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        if command.mode == BatchMode::AllOrNothing && !errors.is_empty() {
+            let violations = errors
+                .into_iter()
+                .map(|error| Violation {
+                    code: error.code,
+                    field: Some(format!("items[{}].{}", error.index, error.field)),
+                    message: error.message,
+                })
+                .collect();
+            return Err(ApplicationError::ValidationError(violations));
+        }
+
+        let items = if items.is_empty() {
+            items
+        } else {
+            self.store(items).await?
+        };
+
+        let mut tables = BTreeMap::<i64, Vec<ItemModel>>::new();
+        for item in items {
+            tables
+                .entry(item.table_id)
+                .or_default()
+                .push(ItemModel::from(item));
+        }
+
+        let tables = tables
+            .into_iter()
+            .map(|(table_id, items)| BatchTableModel { table_id, items })
+            .collect();
+
+        Ok(BatchModel { tables, errors })
     }
 
+    #[instrument(skip_all, fields(table_id = table_id, item_id = %item_id))]
     async fn get_item(&self, table_id: i64, item_id: Uuid) -> Result<ItemModel, ApplicationError> {
         info!(
             "Getting item with id: {:?} for table: {:?}",
@@ -91,12 +310,54 @@ impl ItemService for ItemServiceImpl {
         Ok(item)
     }
 
-    async fn get_items(&self, table_id: i64) -> Result<Vec<ItemModel>, ApplicationError> {
-        info!("Getting items for table: {:?}", table_id);
+    #[instrument(skip_all, fields(table_id = table_id))]
+    async fn get_items(
+        &self,
+        table_id: i64,
+        query: ItemQuery,
+    ) -> Result<ItemModelPage, ApplicationError> {
+        info!(
+            "Getting items for table: {:?} with query: {:?}",
+            table_id, query
+        );
+
+        if query.limit == 0 || query.limit > MAX_PAGE_SIZE {
+            return Err(ApplicationError::invalid(
+                Violation::new(
+                    ErrorCode::InvalidLimit,
+                    format!("Limit must be between 1 and {}.", MAX_PAGE_SIZE),
+                )
+                .with_field("limit"),
+            ));
+        }
+
+        if let (Some(after), Some(before)) = (query.prepared_after, query.prepared_before) {
+            if after >= before {
+                return Err(ApplicationError::invalid(Violation::new(
+                    ErrorCode::EmptyTimeWindow,
+                    "Preparation time window is empty.",
+                )));
+            }
+        }
+
+        let page = self
+            .repository
+            .find_items_by_table(&table_id, &query)
+            .await?;
+
+        Ok(ItemModelPage::from(page))
+    }
+
+    #[instrument(skip_all, fields(station = %station))]
+    async fn get_station_items(
+        &self,
+        station: Station,
+    ) -> Result<Vec<ItemModel>, ApplicationError> {
+        info!("Getting items for station: {}", station);
 
         let models = self
             .repository
-            .find_items_by_table(&table_id)
+            .find_items_by_station(&station)
             .await?
             .into_iter()
             .map(ItemModel::from)
@@ -105,27 +366,92 @@ impl ItemService for ItemServiceImpl {
         Ok(models)
     }
 
+    #[instrument(skip_all, fields(table_id = table_id, item_id = %item_id))]
+    async fn update_item(
+        &self,
+        table_id: i64,
+        item_id: Uuid,
+        command: UpdateItemCommand,
+    ) -> Result<ItemModel, ApplicationError> {
+        info!(
+            "Updating item with id: {:?} for table: {:?} with command: {}",
+            item_id,
+            table_id,
+            self.payload_logging.format(&command)
+        );
+
+        if command.name.is_none() && command.notes.is_none() {
+            return Err(ApplicationError::invalid(Violation::new(
+                ErrorCode::NothingToUpdate,
+                "Nothing to update.",
+            )));
+        }
+
+        let item = self
+            .repository
+            .find_item(&table_id, &item_id)
+            .await?
+            .ok_or(ApplicationError::ResourceNotFound)?;
+
+        let now = Utc::now().round_subsecs(6);
+        let cook_duration = self.cook_duration(&item.station);
+        if item.has_started_preparation(now, cook_duration) {
+            return Err(ApplicationError::Conflict(PREPARATION_STARTED.to_string()));
+        }
+
+        let updated = Item::try_new(
+            item.id,
+            item.table_id,
+            command.name.unwrap_or(item.name),
+            item.preparation_time,
+            item.station,
+        )?
+        .with_notes(command.notes.unwrap_or(item.notes))?;
+
+        self.repository
+            .update_item(&updated, &(now + cook_duration))
+            .await?
+            .map(ItemModel::from)
+            .ok_or(ApplicationError::Conflict(PREPARATION_STARTED.to_string()))
+    }
+
+    #[instrument(skip_all, fields(table_id = table_id, item_id = %item_id))]
     async fn delete_item(&self, table_id: i64, item_id: Uuid) -> Result<(), ApplicationError> {
         info!(
             "Deleting item with id: {:?} for table: {:?}",
             item_id, table_id
         );
 
-        self.repository.delete_item(&table_id, &item_id).await?;
+        if !self.repository.delete_item(&table_id, &item_id).await? {
+            return Err(ApplicationError::ResourceNotFound);
+        }
+
+        if let Some(metrics) = &self.metrics {
+            metrics.item_deleted(table_id);
+        }
 
         Ok(())
     }
 }
 
+// The cause is logged by the repository and is not passed on, so it never reaches a response.
 impl From<RepositoryError> for ApplicationError {
-    fn from(_: RepositoryError) -> Self {
-        ApplicationError::InternalError
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::Unavailable(_) => ApplicationError::Unavailable,
+            RepositoryError::Conflict(_) => ApplicationError::Conflict(
+                "Request conflicts with the current state of the resource.".to_string(),
+            ),
+            RepositoryError::InternalRepositoryError(_) | RepositoryError::MappingError(_) => {
+                ApplicationError::InternalError
+            }
+        }
     }
 }
 
 impl From<ItemValidationError> for ApplicationError {
     fn from(error: ItemValidationError) -> Self {
-        ApplicationError::ValidationError(error.to_string())
+        ApplicationError::invalid(Violation::from(error))
     }
 }
 
@@ -134,9 +460,121 @@ pub struct CreateItemsCommand {
     pub items: Vec<CreateItemModel>,
 }
 
+impl Redact for CreateItemsCommand {
+    fn redact(&self) -> String {
+        format!("CreateItemsCommand {{ items: {} }}", self.items.len())
+    }
+}
+
 #[derive(Debug, Deserialize, Eq, PartialEq)]
 pub struct CreateItemModel {
     pub name: String,
+    #[serde(default)]
+    pub station: Option<Station>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    #[default]
+    AllOrNothing,
+    Partial,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct BatchCreateItemsCommand {
+    #[serde(default)]
+    pub mode: BatchMode,
+    pub items: Vec<BatchCreateItemModel>,
+}
+
+impl Redact for BatchCreateItemsCommand {
+    fn redact(&self) -> String {
+        format!(
+            "BatchCreateItemsCommand {{ mode: {:?}, items: {} }}",
+            self.mode,
+            self.items.len()
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct BatchCreateItemModel {
+    pub table_id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub station: Option<Station>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BatchModel {
+    pub tables: Vec<BatchTableModel>,
+    pub errors: Vec<BatchErrorModel>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BatchTableModel {
+    pub table_id: i64,
+    pub items: Vec<ItemModel>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchErrorModel {
+    pub index: usize,
+    pub table_id: i64,
+    pub code: ErrorCode,
+    pub field: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct UpdateItemCommand {
+    #[serde(default)]
+    pub name: Option<String>,
+    // A missing field keeps the notes, while `null` clears them.
+    #[serde(default, deserialize_with = "present")]
+    pub notes: Option<Option<String>>,
+}
+
+fn present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+impl Redact for UpdateItemCommand {
+    fn redact(&self) -> String {
+        let redacted = |value: &Option<String>| match value {
+            Some(_) => "Some(<redacted>)",
+            None => "None",
+        };
+        let notes = match &self.notes {
+            Some(notes) => format!("Some({})", redacted(notes)),
+            None => "None".to_string(),
+        };
+        format!(
+            "UpdateItemCommand {{ name: {}, notes: {} }}",
+            redacted(&self.name),
+            notes
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ItemModelPage {
+    pub items: Vec<ItemModel>,
+    pub next: Option<ItemCursor>,
+}
+
+impl From<ItemPage> for ItemModelPage {
+    fn from(page: ItemPage) -> Self {
+        Self {
+            items: page.items.into_iter().map(ItemModel::from).collect(),
+            next: page.next,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize)]
@@ -145,6 +583,9 @@ pub struct ItemModel {
     pub table_id: i64,
     pub name: String,
     pub preparation_time: DateTime<Utc>,
+    pub station: Station,
+    pub status: ItemStatus,
+    pub notes: Option<String>,
 }
 
 impl From<Item> for ItemModel {
@@ -154,6 +595,9 @@ impl From<Item> for ItemModel {
             table_id: value.table_id,
             name: value.name,
             preparation_time: value.preparation_time,
+            station: value.station,
+            status: value.status,
+            notes: value.notes,
         }
     }
 }
@@ -1 +1,5 @@
+pub mod auto_advance_service;
+pub mod error_code;
 pub mod item_service;
+pub mod overdue_service;
+pub mod payload_logging;
@@ -0,0 +1,89 @@
+use crate::application::item_service::{ApplicationError, ItemModel};
+use crate::domain::events::{EventPublisher, ItemEvent};
+use crate::domain::item::OverduePolicy;
+use crate::domain::repository::ItemRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use mockall::automock;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+#[automock]
+#[async_trait]
+pub trait OverdueService {
+    async fn get_overdue_items(&self) -> Result<Vec<ItemModel>, ApplicationError>;
+    async fn notify_overdue_items(&self) -> Result<usize, ApplicationError>;
+}
+
+pub struct OverdueServiceImpl {
+    repository: Arc<dyn ItemRepository + Send + Sync>,
+    publisher: Arc<dyn EventPublisher + Send + Sync>,
+    overdue: OverduePolicy,
+}
+
+impl OverdueServiceImpl {
+    pub fn new(
+        repository: Arc<dyn ItemRepository + Send + Sync>,
+        publisher: Arc<dyn EventPublisher + Send + Sync>,
+        overdue: OverduePolicy,
+    ) -> Self {
+        Self {
+            repository,
+            publisher,
+            overdue,
+        }
+    }
+}
+
+#[async_trait]
+impl OverdueService for OverdueServiceImpl {
+    async fn get_overdue_items(&self) -> Result<Vec<ItemModel>, ApplicationError> {
+        info!("Getting overdue items");
+
+        let before = self.overdue.cutoff(Utc::now());
+        let models = self
+            .repository
+            .find_overdue_items(&before)
+            .await?
+            .into_iter()
+            .map(ItemModel::from)
+            .collect();
+
+        Ok(models)
+    }
+
+    async fn notify_overdue_items(&self) -> Result<usize, ApplicationError> {
+        let before = self.overdue.cutoff(Utc::now());
+        let items = self
+            .repository
+            .find_unnotified_overdue_items(&before)
+            .await?;
+
+        // Items are only marked once their event is published, so unpublished ones are picked up
+        // again by the next run. Instances running the job at the same time may both publish one.
+        let mut published = Vec::with_capacity(items.len());
+        let mut failure = None;
+        for item in &items {
+            match self.publisher.publish(ItemEvent::overdue(item)) {
+                Ok(()) => published.push(item.id),
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            }
+        }
+
+        if !published.is_empty() {
+            self.repository.mark_overdue_notified(&published).await?;
+            info!("Notified about {} overdue items", published.len());
+        }
+
+        match failure {
+            Some(e) => {
+                warn!("Failed to publish overdue events. Error: {}", e);
+                Err(ApplicationError::Unavailable)
+            }
+            None => Ok(published.len()),
+        }
+    }
+}
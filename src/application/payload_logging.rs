@@ -0,0 +1,100 @@
+use std::fmt::Debug;
+
+// Command payloads carry free text entered by the staff, so how much of them reaches the logs is
+// configurable.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayloadLogging {
+    Full,
+    Truncated(usize),
+    Redacted,
+}
+
+impl Default for PayloadLogging {
+    fn default() -> Self {
+        PayloadLogging::Truncated(256)
+    }
+}
+
+// Describes a payload without any of the values entered by the staff.
+pub trait Redact {
+    fn redact(&self) -> String;
+}
+
+impl PayloadLogging {
+    pub fn format<T: Debug + Redact>(&self, payload: &T) -> String {
+        match self {
+            PayloadLogging::Full => format!("{:?}", payload),
+            PayloadLogging::Truncated(max_length) => {
+                truncate(format!("{:?}", payload), *max_length)
+            }
+            PayloadLogging::Redacted => payload.redact(),
+        }
+    }
+}
+
+fn truncate(text: String, max_length: usize) -> String {
+    let length = text.chars().count();
+    if length <= max_length {
+        return text;
+    }
+
+    let kept: String = text.chars().take(max_length).collect();
+    format!("{}... ({} more characters)", kept, length - max_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Payload {
+        name: String,
+    }
+
+    impl Redact for Payload {
+        fn redact(&self) -> String {
+            format!(
+                "Payload {{ name: <{} characters> }}",
+                self.name.chars().count()
+            )
+        }
+    }
+
+    fn payload() -> Payload {
+        Payload {
+            name: "Żurek".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_log_full_payload() {
+        assert_eq!(
+            PayloadLogging::Full.format(&payload()),
+            "Payload { name: \"Żurek\" }"
+        );
+    }
+
+    #[test]
+    fn should_truncate_long_payload_on_character_boundary() {
+        assert_eq!(
+            PayloadLogging::Truncated(18).format(&payload()),
+            "Payload { name: \"Ż... (7 more characters)"
+        );
+    }
+
+    #[test]
+    fn should_keep_short_payload() {
+        assert_eq!(
+            PayloadLogging::Truncated(100).format(&payload()),
+            "Payload { name: \"Żurek\" }"
+        );
+    }
+
+    #[test]
+    fn should_redact_payload() {
+        assert_eq!(
+            PayloadLogging::Redacted.format(&payload()),
+            "Payload { name: <5 characters> }"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::item::ItemStatus;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub const SYSTEM_ACTOR: &str = "system";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub item_id: Uuid,
+    pub table_id: i64,
+    pub from_status: ItemStatus,
+    pub to_status: ItemStatus,
+    pub actor: String,
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, SubsecRound, Utc};
+use mockall::automock;
+
+#[automock]
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now().round_subsecs(6)
+    }
+}
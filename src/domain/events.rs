@@ -0,0 +1,45 @@
+use crate::domain::item::Item;
+use crate::domain::station::Station;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use serde::Serialize;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum ItemEvent {
+    #[serde(rename = "item.overdue")]
+    Overdue {
+        item_id: Uuid,
+        table_id: i64,
+        station: Station,
+        preparation_time: DateTime<Utc>,
+    },
+}
+
+impl ItemEvent {
+    pub fn overdue(item: &Item) -> Self {
+        ItemEvent::Overdue {
+            item_id: item.id,
+            table_id: item.table_id,
+            station: item.station,
+            preparation_time: item.preparation_time,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemEvent::Overdue { .. } => "item.overdue",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("No subscriber received the {0} event.")]
+pub struct PublishError(pub &'static str);
+
+#[automock]
+pub trait EventPublisher {
+    fn publish(&self, event: ItemEvent) -> Result<(), PublishError>;
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait HealthCheck {
+    fn name(&self) -> &'static str;
+    async fn check(&self) -> Result<(), String>;
+}
@@ -1,13 +1,23 @@
-use chrono::{DateTime, Utc};
+use crate::domain::station::Station;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub const MAX_NOTES_LENGTH: usize = 500;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Item {
     pub id: Uuid,
     pub table_id: i64,
     pub name: String,
     pub preparation_time: DateTime<Utc>,
+    pub station: Station,
+    pub status: ItemStatus,
+    pub notes: Option<String>,
 }
 
 impl Item {
@@ -16,6 +26,7 @@ impl Item {
         table_id: i64,
         name: String,
         preparation_time: DateTime<Utc>,
+        station: Station,
     ) -> Result<Self, ItemValidationError> {
         if name.is_empty() {
             return Err(ItemValidationError::EmptyName);
@@ -30,16 +41,117 @@ impl Item {
             table_id,
             name,
             preparation_time,
+            station,
+            status: ItemStatus::Pending,
+            notes: None,
         })
     }
+
+    pub fn with_notes(self, notes: Option<String>) -> Result<Self, ItemValidationError> {
+        let notes = notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty());
+
+        if notes
+            .as_ref()
+            .is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH)
+        {
+            return Err(ItemValidationError::NotesTooLong);
+        }
+
+        Ok(Item { notes, ..self })
+    }
+
+    // The kitchen picks an item up `cook_duration` before it is due, after which it can no longer
+    // be edited.
+    pub fn has_started_preparation(&self, now: DateTime<Utc>, cook_duration: TimeDelta) -> bool {
+        self.status != ItemStatus::Pending || self.preparation_time - cook_duration <= now
+    }
+}
+
+// Shared by the overdue listing and the item timing, so both agree on which items are overdue.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OverduePolicy {
+    grace_period: Duration,
+}
+
+impl OverduePolicy {
+    pub const fn new(grace_period: Duration) -> Self {
+        OverduePolicy { grace_period }
+    }
+
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.grace_period
+    }
+
+    // Only items that are still waiting for the kitchen can be overdue.
+    pub fn is_overdue(
+        &self,
+        status: ItemStatus,
+        preparation_time: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        status == ItemStatus::Pending && preparation_time < self.cutoff(now)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    #[default]
+    Pending,
+    Ready,
+}
+
+impl ItemStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemStatus::Pending => "pending",
+            ItemStatus::Ready => "ready",
+        }
+    }
+}
+
+impl Display for ItemStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ItemStatus {
+    type Err = UnknownStatusError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(ItemStatus::Pending),
+            "ready" => Ok(ItemStatus::Ready),
+            _ => Err(UnknownStatusError(value.to_string())),
+        }
+    }
 }
 
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("Unknown item status: {0}.")]
+pub struct UnknownStatusError(pub String);
+
 #[derive(Clone, Debug, Error, Eq, PartialEq)]
 pub enum ItemValidationError {
     #[error("Name cannot be empty.")]
     EmptyName,
     #[error("Table id cannot be negative.")]
     NegativeTableId,
+    #[error("Notes cannot be longer than {} characters.", MAX_NOTES_LENGTH)]
+    NotesTooLong,
+}
+
+impl ItemValidationError {
+    pub fn field(&self) -> &'static str {
+        match self {
+            ItemValidationError::EmptyName => "name",
+            ItemValidationError::NegativeTableId => "table_id",
+            ItemValidationError::NotesTooLong => "notes",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -53,13 +165,15 @@ mod tests {
         let name = "Pierogi".to_string();
         let preparation_time = Utc::now();
 
-        let item = Item::try_new(id, table_id, name.clone(), preparation_time)
+        let item = Item::try_new(id, table_id, name.clone(), preparation_time, Station::Bar)
             .expect("Failed to create item");
 
         assert_eq!(item.id, id);
         assert_eq!(item.table_id, table_id);
         assert_eq!(item.name, name);
         assert_eq!(item.preparation_time, preparation_time);
+        assert_eq!(item.station, Station::Bar);
+        assert_eq!(item.status, ItemStatus::Pending);
     }
 
     #[test]
@@ -69,7 +183,7 @@ mod tests {
         let name = "".to_string();
         let preparation_time = Utc::now();
 
-        let result = Item::try_new(id, table_id, name, preparation_time);
+        let result = Item::try_new(id, table_id, name, preparation_time, Station::Grill);
 
         assert_eq!(result, Err(ItemValidationError::EmptyName));
     }
@@ -81,8 +195,68 @@ mod tests {
         let name = "Pierogi".to_string();
         let preparation_time = Utc::now();
 
-        let result = Item::try_new(id, table_id, name, preparation_time);
+        let result = Item::try_new(id, table_id, name, preparation_time, Station::Grill);
 
         assert_eq!(result, Err(ItemValidationError::NegativeTableId));
     }
+
+    #[test]
+    fn should_normalize_notes() {
+        let item = Item::try_new(
+            Uuid::new_v4(),
+            1,
+            "Pierogi".to_string(),
+            Utc::now(),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+
+        let with_notes = item
+            .clone()
+            .with_notes(Some(" No onions ".to_string()))
+            .expect("Failed to set notes");
+        let blank_notes = item
+            .with_notes(Some("  ".to_string()))
+            .expect("Failed to set notes");
+
+        assert_eq!(with_notes.notes, Some("No onions".to_string()));
+        assert_eq!(blank_notes.notes, None);
+    }
+
+    #[test]
+    fn should_not_accept_too_long_notes() {
+        let item = Item::try_new(
+            Uuid::new_v4(),
+            1,
+            "Pierogi".to_string(),
+            Utc::now(),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+
+        let result = item.with_notes(Some("a".repeat(MAX_NOTES_LENGTH + 1)));
+
+        assert_eq!(result, Err(ItemValidationError::NotesTooLong));
+    }
+
+    #[test]
+    fn should_detect_started_preparation() {
+        let now = Utc::now();
+        let item = Item::try_new(
+            Uuid::new_v4(),
+            1,
+            "Pierogi".to_string(),
+            now + TimeDelta::minutes(15),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+        let ready = Item {
+            status: ItemStatus::Ready,
+            ..item.clone()
+        };
+
+        assert!(!item.has_started_preparation(now, TimeDelta::minutes(10)));
+        assert!(item.has_started_preparation(now, TimeDelta::minutes(15)));
+        assert!(ready.has_started_preparation(now, TimeDelta::minutes(10)));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::domain::item::{Item, ItemValidationError};
+use crate::domain::station::{Menu, Station};
 use chrono::{DateTime, SubsecRound, Utc};
 use mockall::automock;
 use rand::Rng;
@@ -7,13 +8,24 @@ use uuid::Uuid;
 
 #[automock]
 pub trait ItemFactory {
-    fn try_create(&self, table_id: i64, name: String) -> Result<Item, ItemValidationError>;
+    fn try_create(
+        &self,
+        table_id: i64,
+        name: String,
+        station: Option<Station>,
+    ) -> Result<Item, ItemValidationError>;
 }
 
 #[derive(Default, Debug)]
-pub struct ItemFactoryImpl;
+pub struct ItemFactoryImpl {
+    menu: Menu,
+}
 
 impl ItemFactoryImpl {
+    pub fn new(menu: Menu) -> Self {
+        Self { menu }
+    }
+
     fn get_random_preparation_time() -> DateTime<Utc> {
         let offset = rand::thread_rng().gen_range(300..901);
         Utc::now().round_subsecs(6) + Duration::from_secs(offset)
@@ -21,9 +33,15 @@ impl ItemFactoryImpl {
 }
 
 impl ItemFactory for ItemFactoryImpl {
-    fn try_create(&self, table_id: i64, name: String) -> Result<Item, ItemValidationError> {
+    fn try_create(
+        &self,
+        table_id: i64,
+        name: String,
+        station: Option<Station>,
+    ) -> Result<Item, ItemValidationError> {
         let id = Uuid::now_v7();
         let preparation_time = Self::get_random_preparation_time();
-        Item::try_new(id, table_id, name, preparation_time)
+        let station = station.unwrap_or_else(|| self.menu.station_for(&name));
+        Item::try_new(id, table_id, name, preparation_time, station)
     }
 }
@@ -0,0 +1,59 @@
+use crate::domain::item::Item;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ItemCursor {
+    pub preparation_time: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl From<&Item> for ItemCursor {
+    fn from(item: &Item) -> Self {
+        ItemCursor {
+            preparation_time: item.preparation_time,
+            id: item.id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ItemQuery {
+    pub name: Option<String>,
+    pub prepared_after: Option<DateTime<Utc>>,
+    pub prepared_before: Option<DateTime<Utc>>,
+    pub order: SortOrder,
+    pub after: Option<ItemCursor>,
+    pub limit: usize,
+}
+
+impl Default for ItemQuery {
+    fn default() -> Self {
+        ItemQuery {
+            name: None,
+            prepared_after: None,
+            prepared_before: None,
+            order: SortOrder::default(),
+            after: None,
+            limit: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ItemPage {
+    pub items: Vec<Item>,
+    pub next: Option<ItemCursor>,
+}
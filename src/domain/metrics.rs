@@ -0,0 +1,7 @@
+use mockall::automock;
+
+#[automock]
+pub trait ItemMetrics {
+    fn items_created(&self, table_id: i64, count: usize);
+    fn item_deleted(&self, table_id: i64);
+}
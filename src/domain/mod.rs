@@ -1,3 +1,11 @@
+pub mod audit;
+pub mod clock;
+pub mod events;
+pub mod health;
 pub mod item;
 pub mod item_factory;
+pub mod item_query;
+pub mod metrics;
+pub mod preparation_estimator;
 pub mod repository;
+pub mod station;
@@ -0,0 +1,260 @@
+use crate::domain::item::Item;
+use crate::domain::repository::{ItemRepository, RepositoryError};
+use crate::domain::station::Station;
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, Utc};
+use mockall::automock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[automock]
+#[async_trait]
+pub trait PreparationTimeEstimator {
+    async fn estimate(&self, items: Vec<Item>) -> Result<Vec<Item>, RepositoryError>;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KitchenCapacity {
+    pub cook_slots: usize,
+    pub cook_duration: Duration,
+}
+
+impl Default for KitchenCapacity {
+    fn default() -> Self {
+        Self {
+            cook_slots: 4,
+            cook_duration: Duration::from_secs(600),
+        }
+    }
+}
+
+pub struct CapacityPreparationTimeEstimator {
+    repository: Arc<dyn ItemRepository + Send + Sync>,
+    capacities: HashMap<Station, KitchenCapacity>,
+}
+
+impl CapacityPreparationTimeEstimator {
+    pub fn new(
+        repository: Arc<dyn ItemRepository + Send + Sync>,
+        capacities: HashMap<Station, KitchenCapacity>,
+    ) -> Self {
+        Self {
+            repository,
+            capacities,
+        }
+    }
+
+    fn capacity_for(&self, station: &Station) -> KitchenCapacity {
+        self.capacities.get(station).cloned().unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl PreparationTimeEstimator for CapacityPreparationTimeEstimator {
+    async fn estimate(&self, items: Vec<Item>) -> Result<Vec<Item>, RepositoryError> {
+        let now = Utc::now().round_subsecs(6);
+        let pending = self.repository.find_pending_items(&now).await?;
+
+        let mut schedules = HashMap::new();
+        for station in items.iter().map(|item| item.station) {
+            if schedules.contains_key(&station) {
+                continue;
+            }
+
+            let queue = pending
+                .iter()
+                .filter(|item| item.station == station)
+                .map(|item| item.preparation_time)
+                .collect::<Vec<_>>();
+            let count = items.iter().filter(|item| item.station == station).count();
+            let times =
+                estimate_preparation_times(now, &queue, count, &self.capacity_for(&station));
+
+            schedules.insert(station, times.into_iter());
+        }
+
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let preparation_time = schedules
+                    .get_mut(&item.station)
+                    .and_then(Iterator::next)
+                    .unwrap_or(item.preparation_time);
+
+                Item {
+                    preparation_time,
+                    ..item
+                }
+            })
+            .collect();
+
+        Ok(items)
+    }
+}
+
+// Each cook slot works through its tickets one at a time, so it frees up once its last pending
+// ticket is done. Every new item takes the earliest free slot and finishes `cook_duration` later.
+pub fn estimate_preparation_times(
+    now: DateTime<Utc>,
+    queue: &[DateTime<Utc>],
+    count: usize,
+    capacity: &KitchenCapacity,
+) -> Vec<DateTime<Utc>> {
+    let slots = capacity.cook_slots.max(1);
+
+    let mut pending = queue
+        .iter()
+        .copied()
+        .filter(|time| *time > now)
+        .collect::<Vec<_>>();
+    pending.sort_unstable();
+
+    let mut free_at = vec![now; slots.saturating_sub(pending.len())];
+    free_at.extend(pending.into_iter().rev().take(slots));
+
+    (0..count)
+        .map(|_| {
+            let (slot, start) = free_at
+                .iter()
+                .copied()
+                .enumerate()
+                .min_by_key(|(_, time)| *time)
+                .expect("At least one cook slot is always available");
+
+            let finish = start + capacity.cook_duration;
+            free_at[slot] = finish;
+            finish
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repository::MockItemRepository;
+    use chrono::TimeDelta;
+    use uuid::Uuid;
+
+    fn capacity(cook_slots: usize) -> KitchenCapacity {
+        KitchenCapacity {
+            cook_slots,
+            cook_duration: Duration::from_secs(600),
+        }
+    }
+
+    #[test]
+    fn should_start_immediately_in_empty_kitchen() {
+        let now = Utc::now();
+
+        let result = estimate_preparation_times(now, &[], 1, &capacity(2));
+
+        assert_eq!(result, vec![now + TimeDelta::minutes(10)]);
+    }
+
+    #[test]
+    fn should_use_free_slots_in_parallel() {
+        let now = Utc::now();
+
+        let result = estimate_preparation_times(now, &[], 3, &capacity(2));
+
+        assert_eq!(
+            result,
+            vec![
+                now + TimeDelta::minutes(10),
+                now + TimeDelta::minutes(10),
+                now + TimeDelta::minutes(20),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_schedule_behind_busy_slots() {
+        let now = Utc::now();
+        let queue = vec![now + TimeDelta::minutes(5), now + TimeDelta::minutes(8)];
+
+        let result = estimate_preparation_times(now, &queue, 2, &capacity(2));
+
+        assert_eq!(
+            result,
+            vec![now + TimeDelta::minutes(15), now + TimeDelta::minutes(18)]
+        );
+    }
+
+    #[test]
+    fn should_only_wait_for_last_ticket_of_each_slot() {
+        let now = Utc::now();
+        let queue = vec![
+            now + TimeDelta::minutes(5),
+            now + TimeDelta::minutes(10),
+            now + TimeDelta::minutes(15),
+        ];
+
+        let result = estimate_preparation_times(now, &queue, 1, &capacity(2));
+
+        assert_eq!(result, vec![now + TimeDelta::minutes(20)]);
+    }
+
+    #[test]
+    fn should_ignore_completed_items() {
+        let now = Utc::now();
+        let queue = vec![now - TimeDelta::minutes(5), now];
+
+        let result = estimate_preparation_times(now, &queue, 1, &capacity(1));
+
+        assert_eq!(result, vec![now + TimeDelta::minutes(10)]);
+    }
+
+    #[tokio::test]
+    async fn should_schedule_each_station_separately() {
+        let now = Utc::now().round_subsecs(6);
+        let busy_grill = Item::try_new(
+            Uuid::now_v7(),
+            1,
+            "Pierogi".to_string(),
+            now + TimeDelta::hours(1),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+
+        let mut repository = MockItemRepository::new();
+        repository
+            .expect_find_pending_items()
+            .return_const(Ok(vec![busy_grill]))
+            .once();
+
+        let estimator = CapacityPreparationTimeEstimator::new(
+            Arc::new(repository),
+            HashMap::from([(Station::Grill, capacity(1)), (Station::Bar, capacity(1))]),
+        );
+
+        let grill_item = Item::try_new(
+            Uuid::now_v7(),
+            1,
+            "Schabowy".to_string(),
+            now,
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+        let bar_item = Item::try_new(Uuid::now_v7(), 1, "Piwo".to_string(), now, Station::Bar)
+            .expect("Failed to create item");
+
+        let result = estimator
+            .estimate(vec![grill_item, bar_item])
+            .await
+            .expect("Failed to estimate");
+
+        assert!(result[0].preparation_time >= now + TimeDelta::minutes(70));
+        assert!(result[1].preparation_time < now + TimeDelta::minutes(70));
+    }
+
+    #[test]
+    fn should_treat_zero_slots_as_single_slot() {
+        let now = Utc::now();
+        let queue = vec![now + TimeDelta::minutes(5)];
+
+        let result = estimate_preparation_times(now, &queue, 1, &capacity(0));
+
+        assert_eq!(result, vec![now + TimeDelta::minutes(15)]);
+    }
+}
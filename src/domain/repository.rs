@@ -1,5 +1,9 @@
+use crate::domain::audit::AuditEntry;
 use crate::domain::item::Item;
+use crate::domain::item_query::{ItemPage, ItemQuery};
+use crate::domain::station::Station;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::automock;
 use uuid::Uuid;
 
@@ -11,13 +15,43 @@ pub trait ItemRepository {
         table_id: &i64,
         item_id: &Uuid,
     ) -> Result<Option<Item>, RepositoryError>;
-    async fn find_items_by_table(&self, table_id: &i64) -> Result<Vec<Item>, RepositoryError>;
+    async fn find_items_by_table(
+        &self,
+        table_id: &i64,
+        query: &ItemQuery,
+    ) -> Result<ItemPage, RepositoryError>;
+    async fn find_items_by_station(&self, station: &Station) -> Result<Vec<Item>, RepositoryError>;
+    async fn find_pending_items(&self, now: &DateTime<Utc>) -> Result<Vec<Item>, RepositoryError>;
+    async fn find_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError>;
+    async fn find_unnotified_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError>;
+    async fn mark_overdue_notified(&self, item_ids: &[Uuid]) -> Result<(), RepositoryError>;
+    async fn advance_ready_items(
+        &self,
+        now: &DateTime<Utc>,
+        actor: &str,
+    ) -> Result<Vec<Item>, RepositoryError>;
+    async fn find_audit_entries(&self, item_id: &Uuid) -> Result<Vec<AuditEntry>, RepositoryError>;
     async fn save_items(&self, item: &[Item]) -> Result<(), RepositoryError>;
-    async fn delete_item(&self, table_id: &i64, item_id: &Uuid) -> Result<(), RepositoryError>;
+    async fn update_item(
+        &self,
+        item: &Item,
+        prepared_after: &DateTime<Utc>,
+    ) -> Result<Option<Item>, RepositoryError>;
+    async fn delete_item(&self, table_id: &i64, item_id: &Uuid) -> Result<bool, RepositoryError>;
 }
 
+// Unavailable errors are transient and worth retrying, conflicts are caused by the request, and
+// the remaining ones point at a bug or at corrupted data.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum RepositoryError {
+    Unavailable(String),
+    Conflict(String),
     InternalRepositoryError(String),
     MappingError(String),
 }
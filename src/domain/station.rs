@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Station {
+    #[default]
+    Grill,
+    Fryer,
+    ColdKitchen,
+    Bar,
+}
+
+impl Station {
+    pub const ALL: [Station; 4] = [
+        Station::Grill,
+        Station::Fryer,
+        Station::ColdKitchen,
+        Station::Bar,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Station::Grill => "grill",
+            Station::Fryer => "fryer",
+            Station::ColdKitchen => "cold_kitchen",
+            Station::Bar => "bar",
+        }
+    }
+}
+
+impl Display for Station {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Station {
+    type Err = UnknownStationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Station::ALL
+            .into_iter()
+            .find(|station| station.as_str() == value)
+            .ok_or_else(|| UnknownStationError(value.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("Unknown station: {0}.")]
+pub struct UnknownStationError(pub String);
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Menu {
+    stations: HashMap<String, Station>,
+    default_station: Station,
+}
+
+impl Menu {
+    pub fn new(stations: HashMap<String, Station>, default_station: Station) -> Self {
+        let stations = stations
+            .into_iter()
+            .map(|(name, station)| (name.to_lowercase(), station))
+            .collect();
+
+        Self {
+            stations,
+            default_station,
+        }
+    }
+
+    pub fn station_for(&self, name: &str) -> Station {
+        self.stations
+            .get(&name.to_lowercase())
+            .copied()
+            .unwrap_or(self.default_station)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_station() {
+        for station in Station::ALL {
+            assert_eq!(Station::from_str(station.as_str()), Ok(station));
+        }
+    }
+
+    #[test]
+    fn should_not_parse_unknown_station() {
+        let result = Station::from_str("oven");
+
+        assert_eq!(result, Err(UnknownStationError("oven".to_string())));
+    }
+
+    #[test]
+    fn should_route_menu_item_to_station() {
+        let menu = Menu::new(
+            HashMap::from([("Piwo".to_string(), Station::Bar)]),
+            Station::Grill,
+        );
+
+        assert_eq!(menu.station_for("piwo"), Station::Bar);
+        assert_eq!(menu.station_for("Pierogi"), Station::Grill);
+    }
+}
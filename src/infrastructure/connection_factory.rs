@@ -1,36 +1,233 @@
+use sqlx::migrate::{MigrateError, Migrator};
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::{Pool, Postgres};
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::Instant;
+use tracing::warn;
 
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/");
+
+#[derive(Default)]
 pub struct DatabaseConfiguration {
-    pub host: String,
-    pub port: u16,
-    pub database: String,
-    pub username: String,
-    pub password: String,
+    pub url: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub database: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub pool: PoolConfiguration,
+    pub retry: ConnectRetryConfiguration,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConnectRetryConfiguration {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub deadline: Duration,
+}
+
+impl Default for ConnectRetryConfiguration {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            deadline: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolConfiguration {
+    pub min_connections: u32,
+    pub max_connections: u32,
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+    pub statement_timeout: Option<Duration>,
+}
+
+impl Default for PoolConfiguration {
+    fn default() -> Self {
+        Self {
+            min_connections: 0,
+            max_connections: 10,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(600)),
+            max_lifetime: Some(Duration::from_secs(1800)),
+            statement_timeout: None,
+        }
+    }
+}
+
+impl PoolConfiguration {
+    pub fn validate(&self) -> Result<(), PoolConfigurationError> {
+        if self.max_connections == 0 {
+            return Err(PoolConfigurationError::NoConnections);
+        }
+
+        if self.min_connections > self.max_connections {
+            return Err(PoolConfigurationError::MinAboveMax);
+        }
+
+        if self.acquire_timeout.is_zero() {
+            return Err(PoolConfigurationError::NoAcquireTimeout);
+        }
+
+        if let (Some(idle_timeout), Some(max_lifetime)) = (self.idle_timeout, self.max_lifetime) {
+            if idle_timeout > max_lifetime {
+                return Err(PoolConfigurationError::IdleAboveLifetime);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum PoolConfigurationError {
+    #[error("Maximum connections must be at least 1.")]
+    NoConnections,
+    #[error("Minimum connections cannot exceed maximum connections.")]
+    MinAboveMax,
+    #[error("Acquire timeout must be greater than zero.")]
+    NoAcquireTimeout,
+    #[error("Idle timeout cannot exceed connection max lifetime.")]
+    IdleAboveLifetime,
+}
+
+#[derive(Debug, Error)]
+pub enum ConnectionError {
+    #[error("Invalid database connection URL. Error: {0}")]
+    Url(sqlx::Error),
+    #[error("Failed to connect to the database within {deadline:?}. Error: {source}")]
+    Connect {
+        deadline: Duration,
+        source: sqlx::Error,
+    },
+    #[error("Failed to migrate the database. Error: {0}")]
+    Migrate(#[from] MigrateError),
 }
 
 pub struct PostgresConnectionPoolFactory;
 
 impl PostgresConnectionPoolFactory {
-    pub async fn create(config: DatabaseConfiguration) -> Pool<Postgres> {
-        let connection_options = PgConnectOptions::new()
-            .host(&config.host)
-            .port(config.port)
-            .database(&config.database)
-            .username(&config.username)
-            .password(&config.password);
-
-        let pool = PgPoolOptions::new()
-            .max_connections(10)
-            .connect_with(connection_options)
-            .await
-            .expect("Failed to create pool");
-
-        sqlx::migrate!("./migrations/")
-            .run(&pool)
-            .await
-            .expect("Failed to migrate database");
-
-        pool
+    pub async fn create(config: DatabaseConfiguration) -> Result<Pool<Postgres>, ConnectionError> {
+        let mut connection_options = match &config.url {
+            Some(url) => PgConnectOptions::from_str(url).map_err(ConnectionError::Url)?,
+            None => PgConnectOptions::new(),
+        };
+        if let Some(host) = &config.host {
+            connection_options = connection_options.host(host);
+        }
+        if let Some(port) = config.port {
+            connection_options = connection_options.port(port);
+        }
+        if let Some(database) = &config.database {
+            connection_options = connection_options.database(database);
+        }
+        if let Some(username) = &config.username {
+            connection_options = connection_options.username(username);
+        }
+        if let Some(password) = &config.password {
+            connection_options = connection_options.password(password);
+        }
+
+        if let Some(statement_timeout) = config.pool.statement_timeout {
+            connection_options = connection_options.options([(
+                "statement_timeout",
+                statement_timeout.as_millis().to_string(),
+            )]);
+        }
+
+        let pool_options = PgPoolOptions::new()
+            .min_connections(config.pool.min_connections)
+            .max_connections(config.pool.max_connections)
+            .acquire_timeout(config.pool.acquire_timeout)
+            .idle_timeout(config.pool.idle_timeout)
+            .max_lifetime(config.pool.max_lifetime);
+
+        let retry = config.retry;
+        let deadline = Instant::now() + retry.deadline;
+        let mut backoff = retry.initial_backoff;
+        let pool = loop {
+            let connect = pool_options
+                .clone()
+                .connect_with(connection_options.clone());
+            let result = tokio::time::timeout_at(deadline, connect)
+                .await
+                .unwrap_or(Err(sqlx::Error::PoolTimedOut));
+
+            match result {
+                Ok(pool) => break pool,
+                Err(e) if Instant::now() + backoff < deadline => {
+                    warn!(
+                        "Failed to connect to the database, retrying in {:?}. Error: {}",
+                        backoff, e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(retry.max_backoff);
+                }
+                Err(e) => {
+                    return Err(ConnectionError::Connect {
+                        deadline: retry.deadline,
+                        source: e,
+                    })
+                }
+            }
+        };
+
+        MIGRATOR.run(&pool).await?;
+
+        Ok(pool)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_accept_default_configuration() {
+        assert_eq!(PoolConfiguration::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_min_above_max() {
+        let config = PoolConfiguration {
+            min_connections: 20,
+            max_connections: 10,
+            ..PoolConfiguration::default()
+        };
+
+        assert_eq!(config.validate(), Err(PoolConfigurationError::MinAboveMax));
+    }
+
+    #[test]
+    fn should_reject_idle_timeout_above_lifetime() {
+        let config = PoolConfiguration {
+            idle_timeout: Some(Duration::from_secs(3600)),
+            max_lifetime: Some(Duration::from_secs(60)),
+            ..PoolConfiguration::default()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(PoolConfigurationError::IdleAboveLifetime)
+        );
+    }
+
+    #[test]
+    fn should_reject_zero_acquire_timeout() {
+        let config = PoolConfiguration {
+            acquire_timeout: Duration::ZERO,
+            ..PoolConfiguration::default()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(PoolConfigurationError::NoAcquireTimeout)
+        );
     }
 }
@@ -0,0 +1,56 @@
+use crate::domain::events::{EventPublisher, ItemEvent, PublishError};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::{Receiver, Sender};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct BroadcastEventPublisher {
+    sender: Sender<ItemEvent>,
+}
+
+impl BroadcastEventPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        BroadcastEventPublisher { sender }
+    }
+
+    pub fn subscribe(&self) -> Receiver<ItemEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl EventPublisher for BroadcastEventPublisher {
+    fn publish(&self, event: ItemEvent) -> Result<(), PublishError> {
+        info!(event = event.name(), "Publishing event: {:?}", event);
+        let name = event.name();
+        self.sender
+            .send(event)
+            .map(|_| ())
+            .map_err(|_| PublishError(name))
+    }
+}
+
+// Writes every published event as a JSON line to the `events` log target, from where the log
+// pipeline forwards it. The sink stops once all publishers are dropped.
+pub struct EventLogSink;
+
+impl EventLogSink {
+    pub fn spawn(mut receiver: Receiver<ItemEvent>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => match serde_json::to_string(&event) {
+                        Ok(json) => info!(target: "events", event = event.name(), "{}", json),
+                        Err(e) => error!("Failed to serialize event {:?}. Error: {:?}", event, e),
+                    },
+                    Err(RecvError::Lagged(missed)) => {
+                        error!("Event log sink fell behind and missed {} events", missed)
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use crate::domain::health::HealthCheck;
+use crate::infrastructure::connection_factory::MIGRATOR;
+use crate::infrastructure::resilient_repository::{CircuitBreaker, CircuitState};
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+pub struct DatabaseHealthCheck {
+    pool: Pool<Postgres>,
+}
+
+impl DatabaseHealthCheck {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        DatabaseHealthCheck { pool }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseHealthCheck {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+// Compares the migrations embedded in the binary with the ones applied to the database, so an
+// instance running against an outdated schema is not considered ready.
+pub struct MigrationHealthCheck {
+    pool: Pool<Postgres>,
+}
+
+impl MigrationHealthCheck {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        MigrationHealthCheck { pool }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for MigrationHealthCheck {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let applied: HashSet<i64> =
+            sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .collect();
+
+        let pending = MIGRATOR
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .filter(|migration| !applied.contains(&migration.version))
+            .map(|migration| migration.version.to_string())
+            .collect::<Vec<_>>();
+
+        if pending.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Pending migrations: {}", pending.join(", ")))
+        }
+    }
+}
+
+// Reports the instance as not ready while database calls are being rejected, so traffic can be
+// routed elsewhere until the breaker lets a trial call through.
+pub struct CircuitBreakerHealthCheck {
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakerHealthCheck {
+    pub fn new(breaker: Arc<CircuitBreaker>) -> Self {
+        CircuitBreakerHealthCheck { breaker }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for CircuitBreakerHealthCheck {
+    fn name(&self) -> &'static str {
+        "circuit_breaker"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        match self.breaker.state() {
+            CircuitState::Open => Err("Circuit breaker is open".to_string()),
+            CircuitState::Closed | CircuitState::HalfOpen => Ok(()),
+        }
+    }
+}
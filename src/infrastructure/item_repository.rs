@@ -1,33 +1,95 @@
-use crate::domain::item::{Item, ItemValidationError};
+use crate::domain::audit::AuditEntry;
+use crate::domain::item::{Item, ItemStatus, ItemValidationError};
+use crate::domain::item_query::{ItemCursor, ItemPage, ItemQuery, SortOrder};
 use crate::domain::repository::{ItemRepository, RepositoryError};
+use crate::domain::station::Station;
+use crate::infrastructure::metrics::PrometheusMetrics;
 use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
 
+use sqlx::error::ErrorKind;
+use sqlx::pool::PoolConnection;
 use sqlx::postgres::PgRow;
-use sqlx::{Pool, Postgres, Row};
-use tracing::error;
+use sqlx::{Connection, PgConnection, Pool, Postgres, QueryBuilder, Row};
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::field::{display, Empty};
+use tracing::{error, instrument, Span};
 use uuid::Uuid;
 
 const QUERY_ITEM: &str =
-    "SELECT id, table_id, name, preparation_time FROM items WHERE id = $2 and table_id = $1";
+    "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE id = $2 and table_id = $1";
 const QUERY_TABLE: &str =
-    "SELECT id, table_id, name, preparation_time FROM items WHERE table_id = $1";
-const INSERT_ITEM: &str =
-    "INSERT INTO items (id, table_id, name, preparation_time) VALUES ($1, $2, $3, $4)";
+    "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE table_id = ";
+const QUERY_STATION: &str = "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE station = $1 AND status = 'pending' ORDER BY preparation_time, id LIMIT $2";
+// A station display only shows its outstanding work, so it is capped rather than paginated.
+const STATION_ITEMS_LIMIT: i64 = 500;
+const QUERY_PENDING: &str =
+    "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE status = 'pending' AND preparation_time > $1";
+const QUERY_OVERDUE: &str = "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE status = 'pending' AND preparation_time < $1 ORDER BY preparation_time, id";
+const QUERY_UNNOTIFIED_OVERDUE: &str = "SELECT id, table_id, name, preparation_time, station, status, notes FROM items WHERE status = 'pending' AND preparation_time < $1 AND overdue_notified_at IS NULL ORDER BY preparation_time, id";
+const MARK_OVERDUE_NOTIFIED: &str =
+    "UPDATE items SET overdue_notified_at = NOW() WHERE id = ANY($1) AND overdue_notified_at IS NULL";
+const ADVANCE_READY: &str = "WITH advanced AS (
+    UPDATE items SET status = 'ready' WHERE status = 'pending' AND preparation_time <= $1
+    RETURNING id, table_id, name, preparation_time, station, status, notes
+), audited AS (
+    INSERT INTO item_audit (item_id, table_id, from_status, to_status, actor, occurred_at)
+    SELECT id, table_id, 'pending', status, $2, $1 FROM advanced
+)
+SELECT id, table_id, name, preparation_time, station, status, notes FROM advanced";
+const QUERY_AUDIT: &str = "SELECT item_id, table_id, from_status, to_status, actor, occurred_at FROM item_audit WHERE item_id = $1 ORDER BY id";
+const INSERT_ITEMS: &str = "INSERT INTO items (id, table_id, name, preparation_time, station, status, notes)
+SELECT * FROM UNNEST($1::uuid[], $2::int8[], $3::varchar[], $4::timestamptz[], $5::varchar[], $6::varchar[], $7::text[])";
+const COPY_ITEMS: &str = "COPY items (id, table_id, name, preparation_time, station, status, notes) FROM STDIN WITH (FORMAT csv)";
+// Below this size a single UNNEST insert is faster than setting up a COPY stream.
+const COPY_THRESHOLD: usize = 500;
+// Larger batches only record their tables, so spans stay small.
+const SPAN_ITEM_IDS_LIMIT: usize = 20;
+const UPDATE_ITEM: &str = "UPDATE items SET name = $3, notes = $4 WHERE id = $2 AND table_id = $1 AND status = 'pending' AND preparation_time > $5 RETURNING id, table_id, name, preparation_time, station, status, notes";
 const DELETE_ITEM: &str = "DELETE FROM items WHERE id = $2 AND table_id = $1";
 
 #[derive(Clone)]
 pub struct ItemRepositoryImpl {
     pool: Pool<Postgres>,
+    metrics: Option<Arc<PrometheusMetrics>>,
 }
 
 impl ItemRepositoryImpl {
     pub fn new(pool: Pool<Postgres>) -> Self {
-        ItemRepositoryImpl { pool }
+        ItemRepositoryImpl {
+            pool,
+            metrics: None,
+        }
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<PrometheusMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    // Connections are acquired explicitly, instead of passing the pool as the executor, so the
+    // time spent waiting for one can be measured.
+    async fn acquire(&self) -> Result<PoolConnection<Postgres>, RepositoryError> {
+        let start = Instant::now();
+        let connection = self
+            .pool
+            .acquire()
+            .await
+            .inspect_err(|e| error!("Failed to acquire connection. Error: {:?}", e))?;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_acquire(start.elapsed());
+        }
+
+        Ok(connection)
     }
 }
 
 #[async_trait]
 impl ItemRepository for ItemRepositoryImpl {
+    #[instrument(skip_all, fields(db.system = "postgresql", table_id = table_id, item_id = %item_id))]
     async fn find_item(
         &self,
         table_id: &i64,
@@ -36,40 +98,203 @@ impl ItemRepository for ItemRepositoryImpl {
         sqlx::query(QUERY_ITEM)
             .bind(table_id)
             .bind(item_id)
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.acquire().await?)
             .await
             .inspect_err(|e| error!("Failed to query item. Error: {:?}", e))?
             .map(Item::try_from)
             .transpose()
     }
 
-    async fn find_items_by_table(&self, table_id: &i64) -> Result<Vec<Item>, RepositoryError> {
-        sqlx::query(QUERY_TABLE)
-            .bind(table_id)
-            .fetch_all(&self.pool)
+    #[instrument(skip_all, fields(db.system = "postgresql", table_id = table_id))]
+    async fn find_items_by_table(
+        &self,
+        table_id: &i64,
+        query: &ItemQuery,
+    ) -> Result<ItemPage, RepositoryError> {
+        let (comparison, direction) = match query.order {
+            SortOrder::Asc => (">", "ASC"),
+            SortOrder::Desc => ("<", "DESC"),
+        };
+
+        let mut builder = QueryBuilder::<Postgres>::new(QUERY_TABLE);
+        builder.push_bind(table_id);
+
+        if let Some(name) = &query.name {
+            builder
+                .push(" AND name ILIKE ")
+                .push_bind(format!("%{}%", escape_like(name)));
+        }
+
+        if let Some(prepared_after) = &query.prepared_after {
+            builder
+                .push(" AND preparation_time >= ")
+                .push_bind(prepared_after);
+        }
+
+        if let Some(prepared_before) = &query.prepared_before {
+            builder
+                .push(" AND preparation_time < ")
+                .push_bind(prepared_before);
+        }
+
+        if let Some(cursor) = &query.after {
+            builder
+                .push(format!(" AND (preparation_time, id) {} (", comparison))
+                .push_bind(cursor.preparation_time)
+                .push(", ")
+                .push_bind(cursor.id)
+                .push(")");
+        }
+
+        builder
+            .push(format!(
+                " ORDER BY preparation_time {}, id {} LIMIT ",
+                direction, direction
+            ))
+            .push_bind(query.limit as i64 + 1);
+
+        let mut items = builder
+            .build()
+            .fetch_all(&mut *self.acquire().await?)
             .await
             .inspect_err(|e| error!("Failed to query table. Error: {:?}", e))?
             .into_iter()
             .map(Item::try_from)
+            .collect::<Result<Vec<Item>, RepositoryError>>()?;
+
+        let next = if items.len() > query.limit {
+            items.truncate(query.limit);
+            items.last().map(ItemCursor::from)
+        } else {
+            None
+        };
+
+        Ok(ItemPage { items, next })
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql", station = %station))]
+    async fn find_items_by_station(&self, station: &Station) -> Result<Vec<Item>, RepositoryError> {
+        sqlx::query(QUERY_STATION)
+            .bind(station.as_str())
+            .bind(STATION_ITEMS_LIMIT)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to query station. Error: {:?}", e))?
+            .into_iter()
+            .map(Item::try_from)
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_pending_items(&self, now: &DateTime<Utc>) -> Result<Vec<Item>, RepositoryError> {
+        sqlx::query(QUERY_PENDING)
+            .bind(now)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to query pending items. Error: {:?}", e))?
+            .into_iter()
+            .map(Item::try_from)
             .collect()
     }
 
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        sqlx::query(QUERY_OVERDUE)
+            .bind(before)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to query overdue items. Error: {:?}", e))?
+            .into_iter()
+            .map(Item::try_from)
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_unnotified_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        sqlx::query(QUERY_UNNOTIFIED_OVERDUE)
+            .bind(before)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to query unnotified overdue items. Error: {:?}", e))?
+            .into_iter()
+            .map(Item::try_from)
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql", item_count = item_ids.len()))]
+    async fn mark_overdue_notified(&self, item_ids: &[Uuid]) -> Result<(), RepositoryError> {
+        sqlx::query(MARK_OVERDUE_NOTIFIED)
+            .bind(item_ids)
+            .execute(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to mark overdue items. Error: {:?}", e))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn advance_ready_items(
+        &self,
+        now: &DateTime<Utc>,
+        actor: &str,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        sqlx::query(ADVANCE_READY)
+            .bind(now)
+            .bind(actor)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to advance ready items. Error: {:?}", e))?
+            .into_iter()
+            .map(Item::try_from)
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql", item_id = %item_id))]
+    async fn find_audit_entries(&self, item_id: &Uuid) -> Result<Vec<AuditEntry>, RepositoryError> {
+        sqlx::query(QUERY_AUDIT)
+            .bind(item_id)
+            .fetch_all(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to query audit entries. Error: {:?}", e))?
+            .into_iter()
+            .map(AuditEntry::try_from)
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(
+        db.system = "postgresql",
+        item_count = items.len(),
+        table_ids = %table_ids(items),
+        item_ids = Empty,
+    ))]
     async fn save_items(&self, items: &[Item]) -> Result<(), RepositoryError> {
-        let mut transaction = self
-            .pool
+        if items.is_empty() {
+            return Ok(());
+        }
+        if items.len() <= SPAN_ITEM_IDS_LIMIT {
+            Span::current().record("item_ids", display(item_ids(items)));
+        }
+
+        let mut connection = self.acquire().await?;
+        let mut transaction = connection
             .begin()
             .await
             .inspect_err(|e| error!("Failed to begin transaction. Error: {:?}", e))?;
 
-        for entity in items {
-            sqlx::query(INSERT_ITEM)
-                .bind(entity.id)
-                .bind(entity.table_id)
-                .bind(&entity.name)
-                .bind(entity.preparation_time)
-                .execute(&mut *transaction)
+        if items.len() < COPY_THRESHOLD {
+            insert_items(&mut transaction, items)
                 .await
-                .inspect_err(|e| error!("Inserting item failed. Error: {:?}", e))?;
+                .inspect_err(|e| error!("Inserting items failed. Error: {:?}", e))?;
+        } else {
+            copy_items(&mut transaction, items)
+                .await
+                .inspect_err(|e| error!("Copying items failed. Error: {:?}", e))?;
         }
 
         transaction
@@ -80,24 +305,79 @@ impl ItemRepository for ItemRepositoryImpl {
         Ok(())
     }
 
-    async fn delete_item(&self, table_id: &i64, item_id: &Uuid) -> Result<(), RepositoryError> {
-        sqlx::query(DELETE_ITEM)
+    #[instrument(skip_all, fields(db.system = "postgresql", table_id = item.table_id, item_id = %item.id))]
+    async fn update_item(
+        &self,
+        item: &Item,
+        prepared_after: &DateTime<Utc>,
+    ) -> Result<Option<Item>, RepositoryError> {
+        sqlx::query(UPDATE_ITEM)
+            .bind(item.table_id)
+            .bind(item.id)
+            .bind(&item.name)
+            .bind(&item.notes)
+            .bind(prepared_after)
+            .fetch_optional(&mut *self.acquire().await?)
+            .await
+            .inspect_err(|e| error!("Failed to update item. Error: {:?}", e))?
+            .map(Item::try_from)
+            .transpose()
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql", table_id = table_id, item_id = %item_id))]
+    async fn delete_item(&self, table_id: &i64, item_id: &Uuid) -> Result<bool, RepositoryError> {
+        let result = sqlx::query(DELETE_ITEM)
             .bind(table_id)
             .bind(item_id)
-            .execute(&self.pool)
+            .execute(&mut *self.acquire().await?)
             .await
             .inspect_err(|e| error!("Failed to delete item. Error: {:?}", e))?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 }
 
 impl From<sqlx::Error> for RepositoryError {
     fn from(error: sqlx::Error) -> Self {
-        RepositoryError::InternalRepositoryError(error.to_string())
+        let cause = error.to_string();
+        match error {
+            sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::WorkerCrashed => RepositoryError::Unavailable(cause),
+            sqlx::Error::Database(e) => match e.kind() {
+                ErrorKind::UniqueViolation | ErrorKind::ForeignKeyViolation => {
+                    RepositoryError::Conflict(cause)
+                }
+                _ if e.code().as_deref().is_some_and(is_transient) => {
+                    RepositoryError::Unavailable(cause)
+                }
+                _ => RepositoryError::InternalRepositoryError(cause),
+            },
+            sqlx::Error::RowNotFound
+            | sqlx::Error::TypeNotFound { .. }
+            | sqlx::Error::ColumnIndexOutOfBounds { .. }
+            | sqlx::Error::ColumnNotFound(_)
+            | sqlx::Error::ColumnDecode { .. }
+            | sqlx::Error::Decode(_) => RepositoryError::MappingError(cause),
+            _ => RepositoryError::InternalRepositoryError(cause),
+        }
     }
 }
 
+// SQLSTATE codes of failures that are expected to go away on their own: connection exceptions,
+// insufficient resources, serialization failures and deadlocks, cancelled statements (including
+// statement timeouts) and a server that is shutting down or starting up.
+fn is_transient(code: &str) -> bool {
+    code.starts_with("08")
+        || code.starts_with("53")
+        || matches!(
+            code,
+            "40001" | "40P01" | "57014" | "57P01" | "57P02" | "57P03"
+        )
+}
+
 impl From<ItemValidationError> for RepositoryError {
     fn from(error: ItemValidationError) -> Self {
         RepositoryError::MappingError(error.to_string())
@@ -112,11 +392,259 @@ impl TryFrom<PgRow> for Item {
         let table_id: i64 = row.try_get(1)?;
         let name: String = row.try_get(2)?;
         let preparation_time = row.try_get(3)?;
+        let station: String = row.try_get(4)?;
+        let station = Station::from_str(&station)
+            .inspect_err(|e| error!("Failed to map station. Error: {:?}", e))
+            .map_err(|e| RepositoryError::MappingError(e.to_string()))?;
+        let status: String = row.try_get(5)?;
+        let status = map_status(&status)?;
+        let notes: Option<String> = row.try_get(6)?;
 
-        let item = Item::try_new(id, table_id, name, preparation_time)
+        let item = Item::try_new(id, table_id, name, preparation_time, station)
             .inspect_err(|e| error!("Failed to create item. Error: {:?}", e))
             .map_err(|e| RepositoryError::MappingError(e.to_string()))?;
 
-        Ok(item)
+        Ok(Item {
+            status,
+            notes,
+            ..item
+        })
+    }
+}
+
+impl TryFrom<PgRow> for AuditEntry {
+    type Error = RepositoryError;
+
+    fn try_from(row: PgRow) -> Result<Self, RepositoryError> {
+        let from_status: String = row.try_get(2)?;
+        let to_status: String = row.try_get(3)?;
+
+        Ok(AuditEntry {
+            item_id: row.try_get(0)?,
+            table_id: row.try_get(1)?,
+            from_status: map_status(&from_status)?,
+            to_status: map_status(&to_status)?,
+            actor: row.try_get(4)?,
+            occurred_at: row.try_get(5)?,
+        })
+    }
+}
+
+async fn insert_items(connection: &mut PgConnection, items: &[Item]) -> Result<(), sqlx::Error> {
+    let mut ids = Vec::with_capacity(items.len());
+    let mut table_ids = Vec::with_capacity(items.len());
+    let mut names = Vec::with_capacity(items.len());
+    let mut preparation_times = Vec::with_capacity(items.len());
+    let mut stations = Vec::with_capacity(items.len());
+    let mut statuses = Vec::with_capacity(items.len());
+    let mut notes = Vec::with_capacity(items.len());
+
+    for item in items {
+        ids.push(item.id);
+        table_ids.push(item.table_id);
+        names.push(item.name.as_str());
+        preparation_times.push(item.preparation_time);
+        stations.push(item.station.as_str());
+        statuses.push(item.status.as_str());
+        notes.push(item.notes.as_deref());
+    }
+
+    sqlx::query(INSERT_ITEMS)
+        .bind(ids)
+        .bind(table_ids)
+        .bind(names)
+        .bind(preparation_times)
+        .bind(stations)
+        .bind(statuses)
+        .bind(notes)
+        .execute(connection)
+        .await?;
+
+    Ok(())
+}
+
+async fn copy_items(connection: &mut PgConnection, items: &[Item]) -> Result<(), sqlx::Error> {
+    let mut data = String::new();
+    for item in items {
+        data.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            item.id,
+            item.table_id,
+            escape_csv(&item.name),
+            item.preparation_time
+                .to_rfc3339_opts(SecondsFormat::Micros, true),
+            item.station.as_str(),
+            item.status.as_str(),
+            item.notes.as_deref().map(escape_csv).unwrap_or_default(),
+        ));
+    }
+
+    let mut copy = connection.copy_in_raw(COPY_ITEMS).await?;
+    copy.send(data.as_bytes()).await?;
+    copy.finish().await?;
+
+    Ok(())
+}
+
+// Quoted values are never read as NULL, which is written as an empty unquoted value.
+fn escape_csv(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn map_status(status: &str) -> Result<ItemStatus, RepositoryError> {
+    ItemStatus::from_str(status)
+        .inspect_err(|e| error!("Failed to map status. Error: {:?}", e))
+        .map_err(|e| RepositoryError::MappingError(e.to_string()))
+}
+
+fn table_ids(items: &[Item]) -> String {
+    items
+        .iter()
+        .map(|item| item.table_id)
+        .collect::<BTreeSet<_>>()
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn item_ids(items: &[Item]) -> String {
+    items
+        .iter()
+        .map(|item| item.id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::error::DatabaseError;
+    use std::borrow::Cow;
+    use std::error::Error as StdError;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug)]
+    struct TestDatabaseError(&'static str);
+
+    impl Display for TestDatabaseError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "database error {}", self.0)
+        }
+    }
+
+    impl StdError for TestDatabaseError {}
+
+    impl DatabaseError for TestDatabaseError {
+        fn message(&self) -> &str {
+            "database error"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+
+        fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            match self.0 {
+                "23505" => ErrorKind::UniqueViolation,
+                "23503" => ErrorKind::ForeignKeyViolation,
+                "23502" => ErrorKind::NotNullViolation,
+                _ => ErrorKind::Other,
+            }
+        }
+    }
+
+    fn database_error(code: &'static str) -> RepositoryError {
+        RepositoryError::from(sqlx::Error::Database(Box::new(TestDatabaseError(code))))
+    }
+
+    #[test]
+    fn should_classify_unavailable_database() {
+        assert!(matches!(
+            RepositoryError::from(sqlx::Error::PoolTimedOut),
+            RepositoryError::Unavailable(_)
+        ));
+        assert!(matches!(
+            RepositoryError::from(sqlx::Error::Io(std::io::Error::from(
+                std::io::ErrorKind::ConnectionRefused
+            ))),
+            RepositoryError::Unavailable(_)
+        ));
+        assert!(matches!(
+            database_error("57014"),
+            RepositoryError::Unavailable(_)
+        ));
+        assert!(matches!(
+            database_error("08006"),
+            RepositoryError::Unavailable(_)
+        ));
+    }
+
+    #[test]
+    fn should_classify_constraint_violations_as_conflicts() {
+        assert!(matches!(
+            database_error("23505"),
+            RepositoryError::Conflict(_)
+        ));
+        assert!(matches!(
+            database_error("23503"),
+            RepositoryError::Conflict(_)
+        ));
+    }
+
+    #[test]
+    fn should_classify_remaining_errors_as_internal() {
+        assert!(matches!(
+            database_error("23502"),
+            RepositoryError::InternalRepositoryError(_)
+        ));
+        assert!(matches!(
+            database_error("42P01"),
+            RepositoryError::InternalRepositoryError(_)
+        ));
+        assert!(matches!(
+            RepositoryError::from(sqlx::Error::ColumnNotFound("notes".to_string())),
+            RepositoryError::MappingError(_)
+        ));
+    }
+
+    #[test]
+    fn should_describe_saved_items_for_spans() {
+        let item = |table_id| {
+            Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "Piwo".to_string(),
+                Utc::now(),
+                Station::Bar,
+            )
+            .expect("Failed to create item")
+        };
+        let items = [item(3), item(1), item(3)];
+
+        assert_eq!(table_ids(&items), "1,3");
+        assert_eq!(
+            item_ids(&items),
+            format!("{},{},{}", items[0].id, items[1].id, items[2].id)
+        );
     }
 }
@@ -0,0 +1,407 @@
+use crate::domain::metrics::ItemMetrics;
+use crate::infrastructure::resilient_repository::{CircuitBreaker, CircuitState};
+use sqlx::{Pool, Postgres};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+// Buckets are stored cumulatively, the way they are exposed.
+#[derive(Default)]
+struct Histogram {
+    buckets: [u64; DURATION_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    fn render(&self, output: &mut String, name: &str, labels: &str) {
+        let separator = if labels.is_empty() { "" } else { "," };
+        for (bucket, bound) in self.buckets.iter().zip(DURATION_BUCKETS) {
+            let _ = writeln!(
+                output,
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name, labels, separator, bound, bucket
+            );
+        }
+        let _ = writeln!(
+            output,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, separator, self.count
+        );
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", labels)
+        };
+        let _ = writeln!(output, "{}_sum{} {}", name, labels, self.sum);
+        let _ = writeln!(output, "{}_count{} {}", name, labels, self.count);
+    }
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct RequestLabels {
+    method: String,
+    route: String,
+    status: u16,
+}
+
+impl RequestLabels {
+    fn render(&self) -> String {
+        format!(
+            "method=\"{}\",route=\"{}\",status=\"{}\"",
+            escape(&self.method),
+            escape(&self.route),
+            self.status
+        )
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    requests: BTreeMap<RequestLabels, Histogram>,
+    rejections: BTreeMap<(String, &'static str), u64>,
+    acquire: Histogram,
+    items_created: BTreeMap<i64, u64>,
+    items_deleted: BTreeMap<i64, u64>,
+    retries: BTreeMap<&'static str, u64>,
+    breaker_rejections: u64,
+}
+
+// Collects metrics in memory and renders them in the Prometheus text format on scrape. Pool and
+// circuit breaker gauges are read at that point.
+#[derive(Default)]
+pub struct PrometheusMetrics {
+    registry: Mutex<Registry>,
+    pool: Option<Pool<Postgres>>,
+    breaker: Option<Arc<CircuitBreaker>>,
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pool(mut self, pool: Pool<Postgres>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    pub fn with_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    pub fn record_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let labels = RequestLabels {
+            method: method.to_string(),
+            route: route.to_string(),
+            status,
+        };
+        self.registry()
+            .requests
+            .entry(labels)
+            .or_default()
+            .observe(duration);
+    }
+
+    pub fn record_rejection(&self, route: &str, reason: &'static str) {
+        *self
+            .registry()
+            .rejections
+            .entry((route.to_string(), reason))
+            .or_default() += 1;
+    }
+
+    pub fn record_acquire(&self, duration: Duration) {
+        self.registry().acquire.observe(duration);
+    }
+
+    pub fn record_retry(&self, operation: &'static str) {
+        *self.registry().retries.entry(operation).or_default() += 1;
+    }
+
+    pub fn record_breaker_rejection(&self) {
+        self.registry().breaker_rejections += 1;
+    }
+
+    pub fn render(&self) -> String {
+        let registry = self.registry();
+        let mut output = String::new();
+
+        header(
+            &mut output,
+            "http_requests_total",
+            "counter",
+            "Number of HTTP requests.",
+        );
+        for (labels, histogram) in &registry.requests {
+            let _ = writeln!(
+                output,
+                "http_requests_total{{{}}} {}",
+                labels.render(),
+                histogram.count
+            );
+        }
+
+        header(
+            &mut output,
+            "http_request_duration_seconds",
+            "histogram",
+            "HTTP request latency.",
+        );
+        for (labels, histogram) in &registry.requests {
+            histogram.render(
+                &mut output,
+                "http_request_duration_seconds",
+                &labels.render(),
+            );
+        }
+
+        header(
+            &mut output,
+            "http_requests_rejected_total",
+            "counter",
+            "Number of HTTP requests rejected by a limit, per reason.",
+        );
+        for ((route, reason), count) in &registry.rejections {
+            let _ = writeln!(
+                output,
+                "http_requests_rejected_total{{route=\"{}\",reason=\"{}\"}} {}",
+                escape(route),
+                reason,
+                count
+            );
+        }
+
+        if let Some(pool) = &self.pool {
+            header(
+                &mut output,
+                "db_pool_connections",
+                "gauge",
+                "Open database connections.",
+            );
+            let _ = writeln!(output, "db_pool_connections {}", pool.size());
+            header(
+                &mut output,
+                "db_pool_idle_connections",
+                "gauge",
+                "Idle database connections.",
+            );
+            let _ = writeln!(output, "db_pool_idle_connections {}", pool.num_idle());
+            header(
+                &mut output,
+                "db_pool_max_connections",
+                "gauge",
+                "Maximum database connections.",
+            );
+            let _ = writeln!(
+                output,
+                "db_pool_max_connections {}",
+                pool.options().get_max_connections()
+            );
+        }
+
+        header(
+            &mut output,
+            "db_pool_acquire_duration_seconds",
+            "histogram",
+            "Time spent waiting for a database connection.",
+        );
+        registry
+            .acquire
+            .render(&mut output, "db_pool_acquire_duration_seconds", "");
+
+        header(
+            &mut output,
+            "db_retries_total",
+            "counter",
+            "Number of retried database reads per operation.",
+        );
+        for (operation, count) in &registry.retries {
+            let _ = writeln!(
+                output,
+                "db_retries_total{{operation=\"{}\"}} {}",
+                operation, count
+            );
+        }
+
+        if let Some(breaker) = &self.breaker {
+            header(
+                &mut output,
+                "db_circuit_breaker_state",
+                "gauge",
+                "Current state of the database circuit breaker.",
+            );
+            let current = breaker.state();
+            for state in CircuitState::ALL {
+                let _ = writeln!(
+                    output,
+                    "db_circuit_breaker_state{{state=\"{}\"}} {}",
+                    state.as_str(),
+                    u8::from(state == current)
+                );
+            }
+        }
+
+        header(
+            &mut output,
+            "db_circuit_breaker_rejections_total",
+            "counter",
+            "Number of database calls rejected by the open circuit breaker.",
+        );
+        let _ = writeln!(
+            output,
+            "db_circuit_breaker_rejections_total {}",
+            registry.breaker_rejections
+        );
+
+        header(
+            &mut output,
+            "items_created_total",
+            "counter",
+            "Number of items created per table.",
+        );
+        for (table_id, count) in &registry.items_created {
+            let _ = writeln!(
+                output,
+                "items_created_total{{table_id=\"{}\"}} {}",
+                table_id, count
+            );
+        }
+
+        header(
+            &mut output,
+            "items_deleted_total",
+            "counter",
+            "Number of items deleted per table.",
+        );
+        for (table_id, count) in &registry.items_deleted {
+            let _ = writeln!(
+                output,
+                "items_deleted_total{{table_id=\"{}\"}} {}",
+                table_id, count
+            );
+        }
+
+        output
+    }
+
+    // A panic while holding the lock can not leave the counters in an invalid state.
+    fn registry(&self) -> MutexGuard<'_, Registry> {
+        self.registry.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ItemMetrics for PrometheusMetrics {
+    fn items_created(&self, table_id: i64, count: usize) {
+        *self.registry().items_created.entry(table_id).or_default() += count as u64;
+    }
+
+    fn item_deleted(&self, table_id: i64) {
+        *self.registry().items_deleted.entry(table_id).or_default() += 1;
+    }
+}
+
+fn header(output: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(output, "# HELP {} {}", name, help);
+    let _ = writeln!(output, "# TYPE {} {}", name, kind);
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_cumulative_histogram() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_acquire(Duration::from_millis(3));
+        metrics.record_acquire(Duration::from_millis(30));
+
+        let output = metrics.render();
+
+        assert!(output.contains("db_pool_acquire_duration_seconds_bucket{le=\"0.005\"} 1"));
+        assert!(output.contains("db_pool_acquire_duration_seconds_bucket{le=\"0.05\"} 2"));
+        assert!(output.contains("db_pool_acquire_duration_seconds_bucket{le=\"+Inf\"} 2"));
+        assert!(output.contains("db_pool_acquire_duration_seconds_count 2"));
+    }
+
+    #[test]
+    fn should_render_item_counters_per_table() {
+        let metrics = PrometheusMetrics::new();
+        metrics.items_created(1, 3);
+        metrics.items_created(1, 2);
+        metrics.item_deleted(2);
+
+        let output = metrics.render();
+
+        assert!(output.contains("# TYPE items_created_total counter"));
+        assert!(output.contains("items_created_total{table_id=\"1\"} 5"));
+        assert!(output.contains("items_deleted_total{table_id=\"2\"} 1"));
+    }
+
+    #[test]
+    fn should_render_circuit_breaker_state() {
+        let breaker = Arc::new(CircuitBreaker::new(1, Duration::from_secs(10)));
+        let metrics = PrometheusMetrics::new().with_circuit_breaker(breaker);
+        metrics.record_retry("find_item");
+        metrics.record_retry("find_item");
+        metrics.record_breaker_rejection();
+
+        let output = metrics.render();
+
+        assert!(output.contains("db_circuit_breaker_state{state=\"closed\"} 1"));
+        assert!(output.contains("db_circuit_breaker_state{state=\"open\"} 0"));
+        assert!(output.contains("db_retries_total{operation=\"find_item\"} 2"));
+        assert!(output.contains("db_circuit_breaker_rejections_total 1"));
+    }
+
+    #[test]
+    fn should_render_rejections_per_route_and_reason() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_rejection("/v1/tables/:table_id/items", "request_timeout");
+        metrics.record_rejection("/v1/tables/:table_id/items", "request_timeout");
+        metrics.record_rejection("unmatched", "overloaded");
+
+        let output = metrics.render();
+
+        assert!(output.contains("# TYPE http_requests_rejected_total counter"));
+        assert!(output.contains(
+            "http_requests_rejected_total{route=\"/v1/tables/:table_id/items\",reason=\"request_timeout\"} 2"
+        ));
+        assert!(output
+            .contains("http_requests_rejected_total{route=\"unmatched\",reason=\"overloaded\"} 1"));
+    }
+
+    #[test]
+    fn should_escape_label_values() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_request("GET", "/a\"b", 200, Duration::ZERO);
+
+        let output = metrics.render();
+
+        assert!(output.contains("route=\"/a\\\"b\""));
+    }
+}
@@ -1,2 +1,6 @@
 pub mod connection_factory;
+pub mod event_publisher;
+pub mod health_check;
 pub mod item_repository;
+pub mod metrics;
+pub mod resilient_repository;
@@ -0,0 +1,386 @@
+use crate::domain::audit::AuditEntry;
+use crate::domain::item::Item;
+use crate::domain::item_query::{ItemPage, ItemQuery};
+use crate::domain::repository::{ItemRepository, RepositoryError};
+use crate::domain::station::Station;
+use crate::infrastructure::metrics::PrometheusMetrics;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::warn;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResilienceConfiguration {
+    pub retry_attempts: u32,
+    pub retry_backoff: Duration,
+    pub retry_max_backoff: Duration,
+    pub failure_threshold: u32,
+    pub open_duration: Duration,
+}
+
+impl Default for ResilienceConfiguration {
+    fn default() -> Self {
+        Self {
+            retry_attempts: 2,
+            retry_backoff: Duration::from_millis(50),
+            retry_max_backoff: Duration::from_secs(1),
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub const ALL: [CircuitState; 3] = [
+        CircuitState::Closed,
+        CircuitState::Open,
+        CircuitState::HalfOpen,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    // Another trial is let through if the previous one did not report back in time, e.g.
+    // because the request was cancelled.
+    HalfOpen { trial_started: Instant },
+}
+
+// The generation changes with every state transition. Outcomes are only recorded for calls
+// admitted in the current generation, so a slow call admitted before the breaker opened can not
+// close it again without a trial.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Admission(u64);
+
+#[derive(Debug)]
+struct Inner {
+    state: State,
+    generation: u64,
+}
+
+impl Inner {
+    fn transition(&mut self, state: State) {
+        self.state = state;
+        self.generation += 1;
+    }
+}
+
+// Opens after `failure_threshold` consecutive transient failures and rejects calls until
+// `open_duration` has passed. A single trial call then decides whether it closes again.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold,
+            open_duration,
+            inner: Mutex::new(Inner {
+                state: State::Closed { failures: 0 },
+                generation: 0,
+            }),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match self.lock().state {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() < until => CircuitState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn try_acquire(&self) -> Option<Admission> {
+        let mut inner = self.lock();
+        let now = Instant::now();
+        match inner.state {
+            State::Closed { .. } => {}
+            State::Open { until } if now < until => return None,
+            State::HalfOpen { trial_started } if now < trial_started + self.open_duration => {
+                return None
+            }
+            State::Open { .. } | State::HalfOpen { .. } => {
+                inner.transition(State::HalfOpen { trial_started: now })
+            }
+        }
+        Some(Admission(inner.generation))
+    }
+
+    fn record_success(&self, admission: Admission) {
+        let mut inner = self.lock();
+        if admission.0 != inner.generation {
+            return;
+        }
+        match inner.state {
+            State::Closed { .. } => inner.state = State::Closed { failures: 0 },
+            State::HalfOpen { .. } => inner.transition(State::Closed { failures: 0 }),
+            State::Open { .. } => {}
+        }
+    }
+
+    fn record_failure(&self, admission: Admission) {
+        let mut inner = self.lock();
+        if admission.0 != inner.generation {
+            return;
+        }
+        let failures = match inner.state {
+            State::Closed { failures } => failures + 1,
+            State::Open { .. } => return,
+            State::HalfOpen { .. } => self.failure_threshold,
+        };
+        if failures >= self.failure_threshold {
+            warn!("Opening circuit breaker after {} failures", failures);
+            inner.transition(State::Open {
+                until: Instant::now() + self.open_duration,
+            });
+        } else {
+            inner.state = State::Closed { failures };
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Wraps any repository. Only unavailability counts against the circuit breaker, as other errors
+// mean the database did answer. Reads are retried, writes are not, since a write that timed out
+// may still have been applied.
+pub struct ResilientItemRepository {
+    inner: Arc<dyn ItemRepository + Send + Sync>,
+    breaker: Arc<CircuitBreaker>,
+    config: ResilienceConfiguration,
+    metrics: Option<Arc<PrometheusMetrics>>,
+}
+
+impl ResilientItemRepository {
+    pub fn new(
+        inner: Arc<dyn ItemRepository + Send + Sync>,
+        breaker: Arc<CircuitBreaker>,
+        config: ResilienceConfiguration,
+    ) -> Self {
+        Self {
+            inner,
+            breaker,
+            config,
+            metrics: None,
+        }
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<PrometheusMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    async fn retry<T, F, Fut>(&self, operation: &'static str, call: F) -> Result<T, RepositoryError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, RepositoryError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.guard(call()).await {
+                Err(RepositoryError::Unavailable(cause))
+                    if attempt < self.config.retry_attempts
+                        && self.breaker.state() != CircuitState::Open =>
+                {
+                    attempt += 1;
+                    let backoff = self.backoff(attempt);
+                    warn!(
+                        "Retrying {} in {:?}, attempt {}. Error: {}",
+                        operation, backoff, attempt, cause
+                    );
+                    if let Some(metrics) = &self.metrics {
+                        metrics.record_retry(operation);
+                    }
+                    tokio::time::sleep(backoff).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn guard<T>(
+        &self,
+        call: impl Future<Output = Result<T, RepositoryError>>,
+    ) -> Result<T, RepositoryError> {
+        let Some(admission) = self.breaker.try_acquire() else {
+            if let Some(metrics) = &self.metrics {
+                metrics.record_breaker_rejection();
+            }
+            return Err(RepositoryError::Unavailable(
+                "Circuit breaker is open".to_string(),
+            ));
+        };
+
+        let result = call.await;
+        match &result {
+            Err(RepositoryError::Unavailable(_)) => self.breaker.record_failure(admission),
+            _ => self.breaker.record_success(admission),
+        }
+        result
+    }
+
+    // Full jitter: a random delay up to the exponentially growing cap, so that instances
+    // retrying after the same failover do not hit the database at the same moment.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let cap = self
+            .config
+            .retry_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.config.retry_max_backoff);
+        rand::thread_rng().gen_range(Duration::ZERO..=cap)
+    }
+}
+
+#[async_trait]
+impl ItemRepository for ResilientItemRepository {
+    async fn find_item(
+        &self,
+        table_id: &i64,
+        item_id: &Uuid,
+    ) -> Result<Option<Item>, RepositoryError> {
+        self.retry("find_item", || self.inner.find_item(table_id, item_id))
+            .await
+    }
+
+    async fn find_items_by_table(
+        &self,
+        table_id: &i64,
+        query: &ItemQuery,
+    ) -> Result<ItemPage, RepositoryError> {
+        self.retry("find_items_by_table", || {
+            self.inner.find_items_by_table(table_id, query)
+        })
+        .await
+    }
+
+    async fn find_items_by_station(&self, station: &Station) -> Result<Vec<Item>, RepositoryError> {
+        self.retry("find_items_by_station", || {
+            self.inner.find_items_by_station(station)
+        })
+        .await
+    }
+
+    async fn find_pending_items(&self, now: &DateTime<Utc>) -> Result<Vec<Item>, RepositoryError> {
+        self.retry("find_pending_items", || self.inner.find_pending_items(now))
+            .await
+    }
+
+    async fn find_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        self.retry("find_overdue_items", || {
+            self.inner.find_overdue_items(before)
+        })
+        .await
+    }
+
+    async fn find_unnotified_overdue_items(
+        &self,
+        before: &DateTime<Utc>,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        self.retry("find_unnotified_overdue_items", || {
+            self.inner.find_unnotified_overdue_items(before)
+        })
+        .await
+    }
+
+    // Marking is idempotent, so it is retried as well.
+    async fn mark_overdue_notified(&self, item_ids: &[Uuid]) -> Result<(), RepositoryError> {
+        self.retry("mark_overdue_notified", || {
+            self.inner.mark_overdue_notified(item_ids)
+        })
+        .await
+    }
+
+    async fn advance_ready_items(
+        &self,
+        now: &DateTime<Utc>,
+        actor: &str,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        self.guard(self.inner.advance_ready_items(now, actor)).await
+    }
+
+    async fn find_audit_entries(&self, item_id: &Uuid) -> Result<Vec<AuditEntry>, RepositoryError> {
+        self.retry("find_audit_entries", || {
+            self.inner.find_audit_entries(item_id)
+        })
+        .await
+    }
+
+    async fn save_items(&self, items: &[Item]) -> Result<(), RepositoryError> {
+        self.guard(self.inner.save_items(items)).await
+    }
+
+    async fn update_item(
+        &self,
+        item: &Item,
+        prepared_after: &DateTime<Utc>,
+    ) -> Result<Option<Item>, RepositoryError> {
+        self.guard(self.inner.update_item(item, prepared_after))
+            .await
+    }
+
+    async fn delete_item(&self, table_id: &i64, item_id: &Uuid) -> Result<bool, RepositoryError> {
+        self.guard(self.inner.delete_item(table_id, item_id)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_stay_open_when_call_admitted_before_opening_succeeds() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(10));
+        let in_flight = breaker.try_acquire().expect("Closed breaker rejected call");
+        let failing = breaker.try_acquire().expect("Closed breaker rejected call");
+
+        breaker.record_failure(failing);
+        breaker.record_success(in_flight);
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(breaker.try_acquire(), None);
+    }
+
+    #[test]
+    fn should_ignore_failures_admitted_before_closing() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        let stale = breaker.try_acquire().expect("Closed breaker rejected call");
+        breaker.record_failure(breaker.try_acquire().expect("Closed breaker rejected call"));
+        let trial = breaker
+            .try_acquire()
+            .expect("Half open breaker rejected trial");
+
+        breaker.record_success(trial);
+        breaker.record_failure(stale);
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
@@ -1,6 +1,15 @@
+use clap::Parser;
+use paidy_submission::server::configuration::CommandLine;
 use paidy_submission::server::web_server::WebServer;
+use std::process::ExitCode;
 
 #[tokio::main]
-async fn main() {
-    WebServer::run().await;
+async fn main() -> ExitCode {
+    match WebServer::run(CommandLine::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
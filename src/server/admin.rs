@@ -0,0 +1,157 @@
+use crate::domain::health::HealthCheck;
+use crate::infrastructure::metrics::PrometheusMetrics;
+use crate::server::shutdown::Shutdown;
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderName, StatusCode};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+// Health and metrics endpoints, served on the admin listener when one is configured so they are not
+// exposed next to the public API.
+pub struct AdminRouter;
+
+impl AdminRouter {
+    pub fn create(health: HealthProbe, metrics: Arc<PrometheusMetrics>) -> Router {
+        Router::new()
+            .route("/health", get(ready))
+            .route("/health/live", get(live))
+            .route("/health/ready", get(ready))
+            .with_state(Arc::new(health))
+            .merge(
+                Router::new()
+                    .route("/metrics", get(render_metrics))
+                    .with_state(metrics),
+            )
+    }
+}
+
+pub struct HealthProbe {
+    shutdown: Shutdown,
+    timeout: Duration,
+    checks: Vec<Arc<dyn HealthCheck + Send + Sync>>,
+}
+
+impl HealthProbe {
+    pub fn new(shutdown: Shutdown, timeout: Duration) -> Self {
+        HealthProbe {
+            shutdown,
+            timeout,
+            checks: Vec::new(),
+        }
+    }
+
+    pub fn with_check(mut self, check: Arc<dyn HealthCheck + Send + Sync>) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    // Checks run concurrently, each bounded by the timeout, so a hanging dependency can not
+    // stall the probe.
+    async fn run(&self) -> BTreeMap<&'static str, CheckResponse> {
+        let mut running = JoinSet::new();
+        for check in &self.checks {
+            let check = check.clone();
+            let timeout = self.timeout;
+            running.spawn(async move {
+                let start = Instant::now();
+                let result = tokio::time::timeout(timeout, check.check())
+                    .await
+                    .unwrap_or_else(|_| Err(format!("Timed out after {} ms", timeout.as_millis())));
+                (check.name(), CheckResponse::new(result, start.elapsed()))
+            });
+        }
+
+        let mut results = BTreeMap::new();
+        while let Some(finished) = running.join_next().await {
+            if let Ok((name, response)) = finished {
+                results.insert(name, response);
+            }
+        }
+        results
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckResponse {
+    pub status: HealthStatus,
+    pub latency_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CheckResponse {
+    fn new(result: Result<(), String>, latency: Duration) -> Self {
+        CheckResponse {
+            status: match result {
+                Ok(()) => HealthStatus::Up,
+                Err(_) => HealthStatus::Down,
+            },
+            latency_ms: latency.as_millis(),
+            error: result.err(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<&'static str, CheckResponse>,
+}
+
+// Dependencies are left to readiness, restarting the process would not fix them and checking
+// them on every probe only adds load.
+async fn live() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: HealthStatus::Up,
+        checks: BTreeMap::new(),
+    })
+}
+
+// Fails as soon as shutdown starts, so load balancers stop routing new requests while the
+// in-flight ones drain.
+async fn ready(State(health): State<Arc<HealthProbe>>) -> (StatusCode, Json<HealthResponse>) {
+    let mut checks = health.run().await;
+    if health.shutdown.is_triggered() {
+        checks.insert(
+            "server",
+            CheckResponse::new(Err("Shutting down".to_string()), Duration::ZERO),
+        );
+    }
+
+    let status = if checks
+        .values()
+        .all(|check| check.status == HealthStatus::Up)
+    {
+        HealthStatus::Up
+    } else {
+        HealthStatus::Down
+    };
+    let code = match status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (code, Json(HealthResponse { status, checks }))
+}
+
+async fn render_metrics(
+    State(metrics): State<Arc<PrometheusMetrics>>,
+) -> ([(HeaderName, &'static str); 1], String) {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        metrics.render(),
+    )
+}
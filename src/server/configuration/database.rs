@@ -0,0 +1,236 @@
+use crate::infrastructure::connection_factory::{
+    ConnectRetryConfiguration, DatabaseConfiguration, PoolConfiguration,
+};
+use crate::infrastructure::resilient_repository::ResilienceConfiguration;
+use crate::server::configuration::{load_optional_duration, load_seconds, Environment, Load};
+use sqlx::postgres::PgConnectOptions;
+use std::str::FromStr;
+use std::time::Duration;
+
+// Connection URL is used as a base that the individual settings override. Without it, all of
+// them are required.
+impl Load<DatabaseConfiguration> for DatabaseConfiguration {
+    fn load(environment: &mut Environment) -> DatabaseConfiguration {
+        let url = environment.optional("PAIDY_DB_URL");
+        if url
+            .as_deref()
+            .is_some_and(|url| PgConnectOptions::from_str(url).is_err())
+        {
+            environment.invalid("PAIDY_DB_URL", "a valid Postgres connection URL");
+        }
+
+        let load = |environment: &mut Environment, name: &str| match url {
+            Some(_) => environment.optional(name),
+            None => Some(environment.required(name)),
+        };
+        let host = load(environment, "PAIDY_DB_HOST");
+        let port = match url {
+            Some(_) => environment.parse("PAIDY_DB_PORT", "a port number"),
+            None => Some(environment.parse_required("PAIDY_DB_PORT", "a port number")),
+        };
+        let database = load(environment, "PAIDY_DB_NAME");
+        let username = load(environment, "PAIDY_DB_USER");
+        let password = load(environment, "PAIDY_DB_PASSWORD");
+
+        DatabaseConfiguration {
+            url,
+            host,
+            port,
+            database,
+            username,
+            password,
+            pool: PoolConfiguration::load(environment),
+            retry: ConnectRetryConfiguration::load(environment),
+        }
+    }
+}
+
+// Idle timeout, max lifetime and statement timeout are disabled by setting them to 0.
+impl Load<PoolConfiguration> for PoolConfiguration {
+    fn load(environment: &mut Environment) -> PoolConfiguration {
+        let default = PoolConfiguration::default();
+        let pool = PoolConfiguration {
+            min_connections: environment
+                .parse("PAIDY_DB_MIN_CONNECTIONS", "a number")
+                .unwrap_or(default.min_connections),
+            max_connections: environment
+                .parse("PAIDY_DB_MAX_CONNECTIONS", "a number")
+                .unwrap_or(default.max_connections),
+            acquire_timeout: load_seconds(environment, "PAIDY_DB_ACQUIRE_TIMEOUT_SECONDS")
+                .unwrap_or(default.acquire_timeout),
+            idle_timeout: load_optional_duration(
+                environment,
+                "PAIDY_DB_IDLE_TIMEOUT_SECONDS",
+                Duration::from_secs,
+            )
+            .unwrap_or(default.idle_timeout),
+            max_lifetime: load_optional_duration(
+                environment,
+                "PAIDY_DB_MAX_LIFETIME_SECONDS",
+                Duration::from_secs,
+            )
+            .unwrap_or(default.max_lifetime),
+            statement_timeout: load_optional_duration(
+                environment,
+                "PAIDY_DB_STATEMENT_TIMEOUT_MILLISECONDS",
+                Duration::from_millis,
+            )
+            .unwrap_or(default.statement_timeout),
+        };
+
+        if let Err(e) = pool.validate() {
+            environment.inconsistent(format!("Invalid connection pool configuration. {}", e));
+        }
+
+        pool
+    }
+}
+
+impl Load<ConnectRetryConfiguration> for ConnectRetryConfiguration {
+    fn load(environment: &mut Environment) -> ConnectRetryConfiguration {
+        let default = ConnectRetryConfiguration::default();
+        ConnectRetryConfiguration {
+            initial_backoff: environment
+                .parse("PAIDY_DB_CONNECT_BACKOFF_MILLISECONDS", "a number")
+                .map(Duration::from_millis)
+                .unwrap_or(default.initial_backoff),
+            max_backoff: load_seconds(environment, "PAIDY_DB_CONNECT_MAX_BACKOFF_SECONDS")
+                .unwrap_or(default.max_backoff),
+            deadline: load_seconds(environment, "PAIDY_DB_CONNECT_DEADLINE_SECONDS")
+                .unwrap_or(default.deadline),
+        }
+    }
+}
+
+impl Load<ResilienceConfiguration> for ResilienceConfiguration {
+    fn load(environment: &mut Environment) -> ResilienceConfiguration {
+        let default = ResilienceConfiguration::default();
+        let config = ResilienceConfiguration {
+            retry_attempts: environment
+                .parse("PAIDY_DB_RETRY_ATTEMPTS", "a number")
+                .unwrap_or(default.retry_attempts),
+            retry_backoff: environment
+                .parse("PAIDY_DB_RETRY_BACKOFF_MILLISECONDS", "a number")
+                .map(Duration::from_millis)
+                .unwrap_or(default.retry_backoff),
+            retry_max_backoff: environment
+                .parse("PAIDY_DB_RETRY_MAX_BACKOFF_MILLISECONDS", "a number")
+                .map(Duration::from_millis)
+                .unwrap_or(default.retry_max_backoff),
+            failure_threshold: environment
+                .parse("PAIDY_DB_BREAKER_FAILURE_THRESHOLD", "a number")
+                .unwrap_or(default.failure_threshold),
+            open_duration: load_seconds(environment, "PAIDY_DB_BREAKER_OPEN_SECONDS")
+                .unwrap_or(default.open_duration),
+        };
+
+        if config.failure_threshold == 0 {
+            environment.invalid("PAIDY_DB_BREAKER_FAILURE_THRESHOLD", "a positive number");
+        }
+        if config.retry_backoff > config.retry_max_backoff {
+            environment.inconsistent(
+                "PAIDY_DB_RETRY_BACKOFF_MILLISECONDS must not exceed PAIDY_DB_RETRY_MAX_BACKOFF_MILLISECONDS",
+            );
+        }
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::configuration::tests::environment;
+    use crate::server::configuration::{ConfigurationError, ConfigurationProblem};
+
+    #[test]
+    fn should_load_database_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_DB_HOST", "localhost"),
+            ("PAIDY_DB_PORT", "5432"),
+            ("PAIDY_DB_NAME", "paidy"),
+            ("PAIDY_DB_USER", "rust"),
+            ("PAIDY_DB_PASSWORD", "secret"),
+        ]);
+
+        let config = DatabaseConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(config.host, Some("localhost".to_string()));
+        assert_eq!(config.port, Some(5432));
+        assert_eq!(config.pool, PoolConfiguration::default());
+    }
+
+    #[test]
+    fn should_report_inconsistent_pool_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_DB_MIN_CONNECTIONS", "20"),
+            ("PAIDY_DB_MAX_CONNECTIONS", "10"),
+        ]);
+
+        PoolConfiguration::load(&mut environment);
+
+        let error = environment.finish().expect_err("Configuration is valid");
+        assert_eq!(
+            error.to_string(),
+            "Invalid configuration:\n  - Invalid connection pool configuration. Minimum connections cannot exceed maximum connections."
+        );
+    }
+
+    #[test]
+    fn should_use_database_url_instead_of_required_settings() {
+        let mut environment = environment(&[("PAIDY_DB_URL", "postgres://rust@localhost/paidy")]);
+
+        let config = DatabaseConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(
+            config.url,
+            Some("postgres://rust@localhost/paidy".to_string())
+        );
+        assert_eq!(config.host, None);
+    }
+
+    #[test]
+    fn should_load_resilience_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_DB_RETRY_ATTEMPTS", "3"),
+            ("PAIDY_DB_RETRY_BACKOFF_MILLISECONDS", "20"),
+            ("PAIDY_DB_RETRY_MAX_BACKOFF_MILLISECONDS", "200"),
+            ("PAIDY_DB_BREAKER_FAILURE_THRESHOLD", "10"),
+            ("PAIDY_DB_BREAKER_OPEN_SECONDS", "30"),
+        ]);
+
+        let config = ResilienceConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(
+            config,
+            ResilienceConfiguration {
+                retry_attempts: 3,
+                retry_backoff: Duration::from_millis(20),
+                retry_max_backoff: Duration::from_millis(200),
+                failure_threshold: 10,
+                open_duration: Duration::from_secs(30),
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_inconsistent_retry_backoff() {
+        let mut environment = environment(&[
+            ("PAIDY_DB_RETRY_BACKOFF_MILLISECONDS", "2000"),
+            ("PAIDY_DB_RETRY_MAX_BACKOFF_MILLISECONDS", "100"),
+        ]);
+
+        ResilienceConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![ConfigurationProblem::Inconsistent(
+                "PAIDY_DB_RETRY_BACKOFF_MILLISECONDS must not exceed PAIDY_DB_RETRY_MAX_BACKOFF_MILLISECONDS"
+                    .to_string()
+            )]))
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::server::configuration::{load_positive, load_seconds, Environment, Load};
+use crate::server::middleware::{LimitsConfiguration, RequestIdConfiguration};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// The Unix socket and the admin listener are optional, the public TCP listener is always bound.
+pub struct ServerConfiguration {
+    pub bind_address: SocketAddr,
+    pub unix_socket: Option<PathBuf>,
+    pub admin_address: Option<SocketAddr>,
+    pub drain_timeout: Duration,
+    pub health_check_timeout: Duration,
+}
+
+impl Default for ServerConfiguration {
+    fn default() -> Self {
+        Self {
+            bind_address: SocketAddr::from(([0, 0, 0, 0], 3000)),
+            unix_socket: None,
+            admin_address: None,
+            drain_timeout: Duration::from_secs(30),
+            health_check_timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Load<ServerConfiguration> for ServerConfiguration {
+    fn load(environment: &mut Environment) -> ServerConfiguration {
+        let default = ServerConfiguration::default();
+        let mut bind_address = environment
+            .parse("PAIDY_SERVER_BIND_ADDRESS", "a socket address")
+            .unwrap_or(default.bind_address);
+        if let Some(port) = environment.parse("PAIDY_SERVER_PORT", "a port number") {
+            bind_address.set_port(port);
+        }
+
+        let admin_address =
+            environment.parse::<SocketAddr>("PAIDY_SERVER_ADMIN_ADDRESS", "a socket address");
+        if admin_address.is_some_and(|admin| admin.port() == bind_address.port()) {
+            environment.inconsistent(
+                "PAIDY_SERVER_ADMIN_ADDRESS must use a different port than the public listener",
+            );
+        }
+
+        ServerConfiguration {
+            bind_address,
+            unix_socket: environment
+                .optional("PAIDY_SERVER_UNIX_SOCKET")
+                .map(PathBuf::from),
+            admin_address,
+            drain_timeout: load_seconds(environment, "PAIDY_SERVER_DRAIN_TIMEOUT_SECONDS")
+                .unwrap_or(default.drain_timeout),
+            health_check_timeout: environment
+                .parse("PAIDY_SERVER_HEALTH_CHECK_TIMEOUT_MILLISECONDS", "a number")
+                .map(Duration::from_millis)
+                .unwrap_or(default.health_check_timeout),
+        }
+    }
+}
+
+impl Load<RequestIdConfiguration> for RequestIdConfiguration {
+    fn load(environment: &mut Environment) -> RequestIdConfiguration {
+        let default = RequestIdConfiguration::default();
+        let max_length = environment
+            .parse("PAIDY_REQUEST_ID_MAX_LENGTH", "a number")
+            .unwrap_or(default.max_length);
+        if max_length == 0 {
+            environment.invalid("PAIDY_REQUEST_ID_MAX_LENGTH", "a positive number");
+        }
+
+        RequestIdConfiguration {
+            header: environment
+                .parse("PAIDY_REQUEST_ID_HEADER", "a valid header name")
+                .unwrap_or(default.header),
+            max_length,
+            invalid: environment
+                .parse("PAIDY_REQUEST_ID_INVALID", "either replace or reject")
+                .unwrap_or(default.invalid),
+        }
+    }
+}
+
+impl Load<LimitsConfiguration> for LimitsConfiguration {
+    fn load(environment: &mut Environment) -> LimitsConfiguration {
+        let default = LimitsConfiguration::default();
+
+        let mut route_timeouts = HashMap::new();
+        for entry in environment
+            .optional("PAIDY_SERVER_ROUTE_TIMEOUTS")
+            .unwrap_or_default()
+            .split(';')
+            .filter(|entry| !entry.trim().is_empty())
+        {
+            let timeout = entry
+                .split_once('=')
+                .and_then(|(route, timeout)| Some((route, timeout.trim().parse::<u64>().ok()?)));
+            match timeout {
+                Some((route, timeout)) if timeout > 0 => {
+                    route_timeouts.insert(route.trim().to_string(), Duration::from_millis(timeout));
+                }
+                _ => environment.invalid(
+                    "PAIDY_SERVER_ROUTE_TIMEOUTS",
+                    "a list of route=milliseconds entries",
+                ),
+            }
+        }
+
+        LimitsConfiguration {
+            request_timeout: Duration::from_millis(load_positive(
+                environment,
+                "PAIDY_SERVER_REQUEST_TIMEOUT_MILLISECONDS",
+                default.request_timeout.as_millis() as u64,
+            )),
+            route_timeouts,
+            max_body_bytes: load_positive(
+                environment,
+                "PAIDY_SERVER_MAX_BODY_BYTES",
+                default.max_body_bytes,
+            ),
+            max_items_per_create: load_positive(
+                environment,
+                "PAIDY_SERVER_MAX_ITEMS_PER_CREATE",
+                default.max_items_per_create,
+            ),
+            max_concurrent_requests: load_positive(
+                environment,
+                "PAIDY_SERVER_MAX_CONCURRENT_REQUESTS",
+                default.max_concurrent_requests,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::configuration::tests::environment;
+    use crate::server::configuration::{ConfigurationError, ConfigurationProblem};
+    use crate::server::middleware::InvalidRequestId;
+
+    #[test]
+    fn should_load_server_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_SERVER_BIND_ADDRESS", "127.0.0.1:8080"),
+            ("PAIDY_SERVER_PORT", "8181"),
+            ("PAIDY_SERVER_UNIX_SOCKET", "/tmp/paidy.sock"),
+            ("PAIDY_SERVER_ADMIN_ADDRESS", "127.0.0.1:9090"),
+            ("PAIDY_SERVER_DRAIN_TIMEOUT_SECONDS", "5"),
+            ("PAIDY_SERVER_HEALTH_CHECK_TIMEOUT_MILLISECONDS", "250"),
+        ]);
+
+        let config = ServerConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(config.bind_address.to_string(), "127.0.0.1:8181");
+        assert_eq!(config.unix_socket, Some(PathBuf::from("/tmp/paidy.sock")));
+        assert_eq!(
+            config.admin_address.map(|address| address.to_string()),
+            Some("127.0.0.1:9090".to_string())
+        );
+        assert_eq!(config.drain_timeout, Duration::from_secs(5));
+        assert_eq!(config.health_check_timeout, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn should_report_admin_listener_on_public_port() {
+        let mut environment = environment(&[("PAIDY_SERVER_ADMIN_ADDRESS", "127.0.0.1:3000")]);
+
+        ServerConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![
+                ConfigurationProblem::Inconsistent(
+                    "PAIDY_SERVER_ADMIN_ADDRESS must use a different port than the public listener"
+                        .to_string()
+                )
+            ]))
+        );
+    }
+
+    #[test]
+    fn should_load_request_id_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_REQUEST_ID_HEADER", "X-Correlation-Id"),
+            ("PAIDY_REQUEST_ID_MAX_LENGTH", "64"),
+            ("PAIDY_REQUEST_ID_INVALID", "reject"),
+        ]);
+
+        let config = RequestIdConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(config.header.as_str(), "x-correlation-id");
+        assert_eq!(config.max_length, 64);
+        assert_eq!(config.invalid, InvalidRequestId::Reject);
+    }
+
+    #[test]
+    fn should_report_invalid_request_id_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_REQUEST_ID_HEADER", "x request id"),
+            ("PAIDY_REQUEST_ID_MAX_LENGTH", "0"),
+            ("PAIDY_REQUEST_ID_INVALID", "ignore"),
+        ]);
+
+        RequestIdConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_REQUEST_ID_MAX_LENGTH".to_string(),
+                    expected: "a positive number".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_REQUEST_ID_HEADER".to_string(),
+                    expected: "a valid header name".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_REQUEST_ID_INVALID".to_string(),
+                    expected: "either replace or reject".to_string(),
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn should_load_limits_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_SERVER_REQUEST_TIMEOUT_MILLISECONDS", "5000"),
+            (
+                "PAIDY_SERVER_ROUTE_TIMEOUTS",
+                "/v1/tables/:table_id/items=2000; /v1/items/overdue = 500",
+            ),
+            ("PAIDY_SERVER_MAX_BODY_BYTES", "65536"),
+            ("PAIDY_SERVER_MAX_ITEMS_PER_CREATE", "20"),
+            ("PAIDY_SERVER_MAX_CONCURRENT_REQUESTS", "64"),
+        ]);
+
+        let config = LimitsConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(
+            config,
+            LimitsConfiguration {
+                request_timeout: Duration::from_secs(5),
+                route_timeouts: HashMap::from([
+                    (
+                        "/v1/tables/:table_id/items".to_string(),
+                        Duration::from_secs(2)
+                    ),
+                    ("/v1/items/overdue".to_string(), Duration::from_millis(500)),
+                ]),
+                max_body_bytes: 65536,
+                max_items_per_create: 20,
+                max_concurrent_requests: 64,
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_limits_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_SERVER_ROUTE_TIMEOUTS", "/v1/items/overdue=soon"),
+            ("PAIDY_SERVER_REQUEST_TIMEOUT_MILLISECONDS", "0"),
+            ("PAIDY_SERVER_MAX_CONCURRENT_REQUESTS", "0"),
+        ]);
+
+        LimitsConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_SERVER_ROUTE_TIMEOUTS".to_string(),
+                    expected: "a list of route=milliseconds entries".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_SERVER_REQUEST_TIMEOUT_MILLISECONDS".to_string(),
+                    expected: "a positive number".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_SERVER_MAX_CONCURRENT_REQUESTS".to_string(),
+                    expected: "a positive number".to_string(),
+                },
+            ]))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use crate::server::configuration::{load_positive, load_seconds, Environment, Load};
+use crate::server::scheduler::{AutoAdvanceConfiguration, OverdueConfiguration};
+use std::time::Duration;
+
+impl Load<OverdueConfiguration> for OverdueConfiguration {
+    fn load(environment: &mut Environment) -> OverdueConfiguration {
+        OverdueConfiguration {
+            check_interval: Duration::from_secs(load_positive(
+                environment,
+                "PAIDY_OVERDUE_CHECK_INTERVAL_SECONDS",
+                30,
+            )),
+            grace_period: load_seconds(environment, "PAIDY_OVERDUE_GRACE_SECONDS")
+                .unwrap_or(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl Load<AutoAdvanceConfiguration> for AutoAdvanceConfiguration {
+    fn load(environment: &mut Environment) -> AutoAdvanceConfiguration {
+        AutoAdvanceConfiguration {
+            enabled: environment
+                .parse("PAIDY_AUTO_ADVANCE_ENABLED", "true or false")
+                .unwrap_or(false),
+            check_interval: Duration::from_secs(load_positive(
+                environment,
+                "PAIDY_AUTO_ADVANCE_INTERVAL_SECONDS",
+                15,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::configuration::tests::environment;
+    use crate::server::configuration::{ConfigurationError, ConfigurationProblem};
+
+    #[test]
+    fn should_report_zero_job_intervals() {
+        let mut environment = environment(&[
+            ("PAIDY_OVERDUE_CHECK_INTERVAL_SECONDS", "0"),
+            ("PAIDY_AUTO_ADVANCE_INTERVAL_SECONDS", "0"),
+        ]);
+
+        OverdueConfiguration::load(&mut environment);
+        AutoAdvanceConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_OVERDUE_CHECK_INTERVAL_SECONDS".to_string(),
+                    expected: "a positive number".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_AUTO_ADVANCE_INTERVAL_SECONDS".to_string(),
+                    expected: "a positive number".to_string(),
+                },
+            ]))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::preparation_estimator::KitchenCapacity;
+use crate::domain::station::{Menu, Station};
+use crate::server::configuration::{load_seconds, Environment, Load};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EstimationStrategy {
+    Random,
+    Capacity,
+}
+
+pub struct EstimationConfiguration {
+    pub strategy: EstimationStrategy,
+    pub capacities: HashMap<Station, KitchenCapacity>,
+}
+
+impl Load<EstimationConfiguration> for EstimationConfiguration {
+    fn load(environment: &mut Environment) -> EstimationConfiguration {
+        let strategy = match environment.optional("PAIDY_ESTIMATION_STRATEGY").as_deref() {
+            Some("capacity") => EstimationStrategy::Capacity,
+            Some("random") | None => EstimationStrategy::Random,
+            Some(_) => {
+                environment.invalid("PAIDY_ESTIMATION_STRATEGY", "either random or capacity");
+                EstimationStrategy::Random
+            }
+        };
+
+        let default = KitchenCapacity::default();
+        let default = KitchenCapacity {
+            cook_slots: environment
+                .parse("PAIDY_KITCHEN_COOK_SLOTS", "a number")
+                .unwrap_or(default.cook_slots),
+            cook_duration: load_seconds(environment, "PAIDY_KITCHEN_COOK_SECONDS")
+                .unwrap_or(default.cook_duration),
+        };
+
+        let capacities = Station::ALL
+            .into_iter()
+            .map(|station| {
+                let prefix = format!("PAIDY_KITCHEN_{}", station.as_str().to_uppercase());
+                let capacity = KitchenCapacity {
+                    cook_slots: environment
+                        .parse(&format!("{}_COOK_SLOTS", prefix), "a number")
+                        .unwrap_or(default.cook_slots),
+                    cook_duration: load_seconds(environment, &format!("{}_COOK_SECONDS", prefix))
+                        .unwrap_or(default.cook_duration),
+                };
+                (station, capacity)
+            })
+            .collect();
+
+        EstimationConfiguration {
+            strategy,
+            capacities,
+        }
+    }
+}
+
+impl Load<Menu> for Menu {
+    fn load(environment: &mut Environment) -> Menu {
+        let default_station = environment
+            .parse("PAIDY_DEFAULT_STATION", "a known station")
+            .unwrap_or_default();
+
+        let mut stations = HashMap::new();
+        for entry in environment
+            .optional("PAIDY_MENU_STATIONS")
+            .unwrap_or_default()
+            .split(';')
+            .filter(|entry| !entry.trim().is_empty())
+        {
+            let Some((name, station)) = entry.split_once('=') else {
+                environment.invalid("PAIDY_MENU_STATIONS", "a list of name=station entries");
+                continue;
+            };
+            let Ok(station) = Station::from_str(station.trim()) else {
+                environment.invalid("PAIDY_MENU_STATIONS", "only referencing known stations");
+                continue;
+            };
+            stations.insert(name.trim().to_string(), station);
+        }
+
+        Menu::new(stations, default_station)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::configuration::tests::environment;
+
+    #[test]
+    fn should_report_bad_menu_entries() {
+        let mut environment = environment(&[("PAIDY_MENU_STATIONS", "Piwo=bar;Frytki=oven")]);
+
+        let menu = Menu::load(&mut environment);
+
+        assert_eq!(menu.station_for("Piwo"), Station::Bar);
+        assert!(environment.finish().is_err());
+    }
+}
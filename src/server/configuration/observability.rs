@@ -0,0 +1,157 @@
+use crate::application::payload_logging::PayloadLogging;
+use crate::server::configuration::{Environment, Load};
+use crate::server::telemetry::{LogFormat, TelemetryConfiguration};
+use axum::http::Uri;
+use tracing_subscriber::EnvFilter;
+
+pub struct LoggingConfiguration {
+    pub filter: String,
+    pub format: LogFormat,
+    pub payload: PayloadLogging,
+}
+
+impl Default for LoggingConfiguration {
+    fn default() -> Self {
+        Self {
+            filter: format!("{}=info", env!("CARGO_CRATE_NAME")),
+            format: LogFormat::default(),
+            payload: PayloadLogging::default(),
+        }
+    }
+}
+
+impl Load<LoggingConfiguration> for LoggingConfiguration {
+    fn load(environment: &mut Environment) -> LoggingConfiguration {
+        let default = LoggingConfiguration::default();
+        let filter = environment
+            .optional("PAIDY_LOG_FILTER")
+            .or_else(|| environment.optional("RUST_LOG"))
+            .unwrap_or(default.filter);
+
+        if EnvFilter::try_new(&filter).is_err() {
+            environment.invalid("PAIDY_LOG_FILTER", "a valid log filter");
+        }
+
+        let max_length = environment.parse("PAIDY_LOG_PAYLOAD_MAX_LENGTH", "a number");
+        let payload = match environment.optional("PAIDY_LOG_PAYLOAD").as_deref() {
+            None => max_length.map_or(default.payload, PayloadLogging::Truncated),
+            Some("full") => PayloadLogging::Full,
+            Some("truncate") => max_length.map_or(default.payload, PayloadLogging::Truncated),
+            Some("redact") => PayloadLogging::Redacted,
+            Some(_) => {
+                environment.invalid("PAIDY_LOG_PAYLOAD", "one of full, truncate or redact");
+                default.payload
+            }
+        };
+
+        LoggingConfiguration {
+            filter,
+            format: environment
+                .parse("PAIDY_LOG_FORMAT", "either text or json")
+                .unwrap_or(default.format),
+            payload,
+        }
+    }
+}
+
+// The standard OpenTelemetry variables are honoured when the application specific ones are not set.
+impl Load<TelemetryConfiguration> for TelemetryConfiguration {
+    fn load(environment: &mut Environment) -> TelemetryConfiguration {
+        let default = TelemetryConfiguration::default();
+        let otlp_endpoint = environment
+            .optional("PAIDY_OTLP_ENDPOINT")
+            .or_else(|| environment.optional("OTEL_EXPORTER_OTLP_ENDPOINT"));
+        if otlp_endpoint.as_deref().is_some_and(|endpoint| {
+            endpoint
+                .parse::<Uri>()
+                .map_or(true, |uri| uri.scheme().is_none() || uri.host().is_none())
+        }) {
+            environment.invalid("PAIDY_OTLP_ENDPOINT", "an absolute URL");
+        }
+
+        TelemetryConfiguration {
+            otlp_endpoint,
+            service_name: environment
+                .optional("PAIDY_OTLP_SERVICE_NAME")
+                .or_else(|| environment.optional("OTEL_SERVICE_NAME"))
+                .unwrap_or(default.service_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::configuration::tests::environment;
+    use crate::server::configuration::{ConfigurationError, ConfigurationProblem};
+
+    #[test]
+    fn should_load_telemetry_configuration() {
+        let mut environment = environment(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://collector:4318"),
+            ("PAIDY_OTLP_SERVICE_NAME", "kitchen"),
+        ]);
+
+        let config = TelemetryConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(
+            config.otlp_endpoint,
+            Some("http://collector:4318".to_string())
+        );
+        assert_eq!(config.service_name, "kitchen");
+    }
+
+    #[test]
+    fn should_report_relative_otlp_endpoint() {
+        let mut environment = environment(&[("PAIDY_OTLP_ENDPOINT", "collector")]);
+
+        TelemetryConfiguration::load(&mut environment);
+
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![ConfigurationProblem::Invalid {
+                name: "PAIDY_OTLP_ENDPOINT".to_string(),
+                expected: "an absolute URL".to_string(),
+            }]))
+        );
+    }
+
+    #[test]
+    fn should_load_logging_configuration() {
+        let mut environment = environment(&[
+            ("PAIDY_LOG_FORMAT", "json"),
+            ("PAIDY_LOG_PAYLOAD", "truncate"),
+            ("PAIDY_LOG_PAYLOAD_MAX_LENGTH", "64"),
+        ]);
+
+        let config = LoggingConfiguration::load(&mut environment);
+
+        assert_eq!(environment.finish(), Ok(()));
+        assert_eq!(config.format, LogFormat::Json);
+        assert_eq!(config.payload, PayloadLogging::Truncated(64));
+    }
+
+    #[test]
+    fn should_report_invalid_logging_configuration() {
+        let mut environment =
+            environment(&[("PAIDY_LOG_FORMAT", "xml"), ("PAIDY_LOG_PAYLOAD", "hide")]);
+
+        let config = LoggingConfiguration::load(&mut environment);
+
+        assert_eq!(config.payload, PayloadLogging::default());
+        assert_eq!(
+            environment.finish(),
+            Err(ConfigurationError(vec![
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_LOG_PAYLOAD".to_string(),
+                    expected: "one of full, truncate or redact".to_string(),
+                },
+                ConfigurationProblem::Invalid {
+                    name: "PAIDY_LOG_FORMAT".to_string(),
+                    expected: "either text or json".to_string(),
+                },
+            ]))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::server::shutdown::Shutdown;
+use axum::Router;
+use hyper::server::conn::http1;
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use hyper_util::service::TowerToHyperService;
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::Path;
+use std::time::Duration;
+use tokio::net::UnixListener;
+use tracing::{debug, error};
+
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+pub struct UnixSocketListener;
+
+impl UnixSocketListener {
+    // A socket left behind by a previous run would make binding fail, anything else at the path
+    // is left alone.
+    pub fn bind(path: &Path) -> io::Result<UnixListener> {
+        if let Ok(metadata) = std::fs::symlink_metadata(path) {
+            if metadata.file_type().is_socket() {
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        UnixListener::bind(path)
+    }
+
+    // Stops accepting connections once shutdown is triggered and returns when the open ones are
+    // drained. Failed accepts, e.g. when running out of file descriptors, are only logged, the
+    // same way axum::serve does, so they do not bring the whole server down.
+    pub async fn serve(listener: UnixListener, app: Router, shutdown: Shutdown) -> io::Result<()> {
+        let connections = GracefulShutdown::new();
+
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.triggered() => break,
+            };
+            let stream = match accepted {
+                Ok((stream, _)) => stream,
+                Err(e) if is_connection_error(&e) => continue,
+                Err(e) => {
+                    error!("Failed to accept Unix socket connection. Error: {}", e);
+                    tokio::select! {
+                        _ = tokio::time::sleep(ACCEPT_ERROR_BACKOFF) => continue,
+                        _ = shutdown.triggered() => break,
+                    }
+                }
+            };
+            let service = TowerToHyperService::new(app.clone());
+            let connection = connections
+                .watch(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+
+            tokio::spawn(async move {
+                if let Err(e) = connection.await {
+                    debug!("Failed to serve Unix socket connection. Error: {}", e);
+                }
+            });
+        }
+
+        drop(listener);
+        connections.shutdown().await;
+        Ok(())
+    }
+}
+
+// Errors caused by a single client going away, which do not affect accepting the next connection.
+fn is_connection_error(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionReset
+    )
+}
@@ -1,59 +1,338 @@
+use crate::application::error_code::ErrorCode;
+use crate::infrastructure::metrics::PrometheusMetrics;
+use crate::web::errors::{ProblemDetails, ServerError, PROBLEM_JSON};
+use crate::web::request_id::RequestId;
 use axum::body::Body;
-use axum::http::{HeaderName, Request};
+use axum::extract::{MatchedPath, State};
+use axum::http::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Request};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry_http::HeaderExtractor;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
 use tower::layer::util::{Identity, Stack};
 use tower::ServiceBuilder;
-use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
-use tower_http::trace::{HttpMakeClassifier, TraceLayer};
-use tracing::{info_span, warn, Span};
+use tower_http::trace::{DefaultOnRequest, HttpMakeClassifier, TraceLayer};
+use tracing::field::Empty;
+use tracing::{info, info_span, warn, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use tracing_subscriber::EnvFilter;
 use uuid::Uuid;
 
-const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+// Incoming ids are kept as long as they are short and only use characters that are safe to log
+// and to put into label values or URLs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestIdConfiguration {
+    pub header: HeaderName,
+    pub max_length: usize,
+    pub invalid: InvalidRequestId,
+}
 
-pub type RequestIdMiddlewareLayer = ServiceBuilder<
-    Stack<PropagateRequestIdLayer, Stack<SetRequestIdLayer<MakeRequestUuid>, Identity>>,
->;
+impl Default for RequestIdConfiguration {
+    fn default() -> Self {
+        Self {
+            header: HeaderName::from_static("x-request-id"),
+            max_length: 128,
+            invalid: InvalidRequestId::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum InvalidRequestId {
+    #[default]
+    Replace,
+    Reject,
+}
+
+impl FromStr for InvalidRequestId {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "replace" => Ok(InvalidRequestId::Replace),
+            "reject" => Ok(InvalidRequestId::Reject),
+            _ => Err(()),
+        }
+    }
+}
+
+impl RequestIdConfiguration {
+    fn is_valid(&self, request_id: &HeaderValue) -> bool {
+        let bytes = request_id.as_bytes();
+        !bytes.is_empty()
+            && bytes.len() <= self.max_length
+            && bytes
+                .iter()
+                .all(|byte| byte.is_ascii_alphanumeric() || b"-_.:".contains(byte))
+    }
+}
 
 pub struct RequestIdMiddleware;
 
 impl RequestIdMiddleware {
-    pub fn create() -> RequestIdMiddlewareLayer {
-        ServiceBuilder::new()
-            .layer(SetRequestIdLayer::new(REQUEST_ID_HEADER, MakeRequestUuid))
-            .layer(PropagateRequestIdLayer::new(REQUEST_ID_HEADER))
+    // Ids are kept when valid and replaced otherwise, unless malformed ids should be rejected.
+    // Either way the response carries the id the request was processed under.
+    pub async fn handle(
+        State(config): State<Arc<RequestIdConfiguration>>,
+        mut request: Request<Body>,
+        next: Next,
+    ) -> Response {
+        let (request_id, malformed) = match request.headers().get(&config.header) {
+            None => (Self::generate(), false),
+            Some(request_id) if config.is_valid(request_id) => (request_id.clone(), false),
+            Some(request_id) => {
+                let generated = Self::generate();
+                warn!(
+                    "Malformed request ID {:?}, replacing it with {:?}",
+                    request_id, generated
+                );
+                (generated, true)
+            }
+        };
+        let id = RequestId(request_id.to_str().unwrap_or_default().to_string());
+
+        let mut response = if malformed && config.invalid == InvalidRequestId::Reject {
+            let message = format!("Malformed {} header.", config.header);
+            id.scope(async {
+                ServerError::BadRequest(ErrorCode::MalformedRequestId, message).into_response()
+            })
+            .await
+        } else {
+            request
+                .headers_mut()
+                .insert(config.header.clone(), request_id.clone());
+            request.extensions_mut().insert(id.clone());
+            id.scope(next.run(request)).await
+        };
+        response
+            .headers_mut()
+            .insert(config.header.clone(), request_id);
+        response
+    }
+
+    fn generate() -> HeaderValue {
+        HeaderValue::from_str(&Uuid::new_v4().to_string()).expect("UUID is a valid header value")
+    }
+}
+
+pub struct ProblemDetailsMiddleware;
+
+impl ProblemDetailsMiddleware {
+    // Error responses are rendered as RFC 7807 problem documents for clients that accept them,
+    // other clients keep receiving the plain error body.
+    pub async fn negotiate(request: Request<Body>, next: Next) -> Response {
+        let accepts_problem = accepts_problem_json(request.headers());
+        let instance = request.uri().path().to_string();
+
+        let mut response = next.run(request).await;
+        let problem = response.extensions_mut().remove::<ProblemDetails>();
+        match problem {
+            Some(problem) if accepts_problem => {
+                let (mut parts, _) = response.into_parts();
+                let body = serde_json::to_vec(&problem.with_instance(instance))
+                    .expect("Problem details are serializable");
+                parts
+                    .headers
+                    .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+                parts.headers.remove(CONTENT_LENGTH);
+                Response::from_parts(parts, Body::from(body))
+            }
+            _ => response,
+        }
     }
 }
 
-pub type TraceMiddlewareLayer =
-    ServiceBuilder<Stack<TraceLayer<HttpMakeClassifier, fn(&Request<Body>) -> Span>, Identity>>;
+fn accepts_problem_json(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|range| range.split(';').next())
+        .any(|media_type| media_type.trim().eq_ignore_ascii_case(PROBLEM_JSON))
+}
+
+// Only applied to the API routes, so that health probes and scrapes keep answering while the
+// server sheds load. Route timeouts are keyed by route template, e.g. `/v1/tables/:table_id/items`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LimitsConfiguration {
+    pub request_timeout: Duration,
+    pub route_timeouts: HashMap<String, Duration>,
+    pub max_body_bytes: usize,
+    pub max_items_per_create: usize,
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for LimitsConfiguration {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(30),
+            route_timeouts: HashMap::new(),
+            max_body_bytes: 1024 * 1024,
+            max_items_per_create: 100,
+            max_concurrent_requests: 512,
+        }
+    }
+}
+
+impl LimitsConfiguration {
+    fn timeout(&self, route: Option<&MatchedPath>) -> Duration {
+        route
+            .and_then(|route| self.route_timeouts.get(route.as_str()))
+            .copied()
+            .unwrap_or(self.request_timeout)
+    }
+}
+
+pub struct TimeoutMiddleware;
+
+impl TimeoutMiddleware {
+    // The handler future is dropped on timeout, which also rolls back any open transaction.
+    pub async fn enforce(
+        State(config): State<Arc<LimitsConfiguration>>,
+        request: Request<Body>,
+        next: Next,
+    ) -> Response {
+        let timeout = config.timeout(request.extensions().get::<MatchedPath>());
+        let path = request.uri().path().to_string();
+        match tokio::time::timeout(timeout, next.run(request)).await {
+            Ok(response) => response,
+            Err(_) => {
+                warn!("Request to {} timed out after {:?}", path, timeout);
+                ServerError::GatewayTimeout.into_response()
+            }
+        }
+    }
+}
+
+pub struct LoadSheddingMiddleware;
+
+impl LoadSheddingMiddleware {
+    // Requests over the limit are rejected right away instead of queueing, so a slow database
+    // can not pile them up. The semaphore is shared by all routes.
+    pub async fn shed(
+        State(permits): State<Arc<Semaphore>>,
+        request: Request<Body>,
+        next: Next,
+    ) -> Response {
+        let Ok(_permit) = permits.try_acquire() else {
+            warn!("Shedding request to {}", request.uri().path());
+            return ServerError::Overloaded.into_response();
+        };
+        next.run(request).await
+    }
+}
+
+pub struct MetricsMiddleware;
+
+impl MetricsMiddleware {
+    // Requests are labelled with the route template rather than the path, so ids do not end up
+    // in label values.
+    pub async fn track(
+        State(metrics): State<Arc<PrometheusMetrics>>,
+        request: Request<Body>,
+        next: Next,
+    ) -> Response {
+        let method = request.method().to_string();
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_else(|| "unmatched".to_string());
+        let start = Instant::now();
+
+        let response = next.run(request).await;
+
+        metrics.record_request(&method, &route, response.status().as_u16(), start.elapsed());
+        // Limits are told apart by the error code the rejection carries.
+        if let Some(code) = response.extensions().get::<ErrorCode>() {
+            if matches!(
+                code,
+                ErrorCode::RequestTimeout
+                    | ErrorCode::Overloaded
+                    | ErrorCode::PayloadTooLarge
+                    | ErrorCode::TooManyItems
+            ) {
+                metrics.record_rejection(&route, code.as_str());
+            }
+        }
+        response
+    }
+}
+
+pub type TraceMiddlewareLayer = ServiceBuilder<
+    Stack<
+        TraceLayer<
+            HttpMakeClassifier,
+            fn(&Request<Body>) -> Span,
+            DefaultOnRequest,
+            fn(&Response, Duration, &Span),
+        >,
+        Identity,
+    >,
+>;
 
 pub struct TraceMiddleware;
 
 impl TraceMiddleware {
     pub fn create() -> TraceMiddlewareLayer {
-        ServiceBuilder::new().layer(TraceLayer::new_for_http().make_span_with(trace_handler))
+        ServiceBuilder::new().layer(
+            TraceLayer::new_for_http()
+                .make_span_with(trace_handler as fn(&Request<Body>) -> Span)
+                .on_response(response_handler as fn(&Response, Duration, &Span)),
+        )
     }
 
-    pub fn filter() -> EnvFilter {
-        EnvFilter::try_from_default_env().unwrap_or(EnvFilter::from(format!(
-            "{}=info",
-            env!("CARGO_CRATE_NAME")
-        )))
+    pub fn filter(directives: &str) -> EnvFilter {
+        EnvFilter::new(directives)
     }
 }
 
 fn trace_handler(request: &Request<Body>) -> Span {
-    match request.headers().get(REQUEST_ID_HEADER) {
-        Some(request_id) => {
-            info_span!("HTTP", "{:#?}", request_id)
-        }
+    let request_id = match request.extensions().get::<RequestId>() {
+        Some(request_id) => request_id.0.clone(),
         None => {
             let request_id = Uuid::new_v4();
             warn!(
                 "Request ID not found in headers, generating new one: {:?}",
                 request_id
             );
-            info_span!("HTTP", "{:#?}", request_id)
+            request_id.to_string()
         }
-    }
+    };
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str())
+        .unwrap_or_default();
+
+    // Status and latency are only known once the response is produced, see response_handler.
+    let span = info_span!(
+        "HTTP",
+        otel.name = %format!("{} {}", request.method(), route),
+        otel.kind = "server",
+        request_id,
+        method = %request.method(),
+        route,
+        path = request.uri().path(),
+        status = Empty,
+        latency_ms = Empty,
+    );
+    // Continues the caller's trace when the request carries a W3C traceparent header.
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(request.headers()));
+    span.set_parent(parent);
+    span
+}
+
+fn response_handler(response: &Response, latency: Duration, span: &Span) {
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency.as_millis() as u64);
+    info!("Finished processing request");
 }
@@ -1,3 +1,8 @@
+pub mod admin;
 pub mod configuration;
+pub mod listener;
 pub mod middleware;
+pub mod scheduler;
+pub mod shutdown;
+pub mod telemetry;
 pub mod web_server;
@@ -0,0 +1,53 @@
+use crate::application::item_service::ApplicationError;
+use crate::server::shutdown::Shutdown;
+use std::future::Future;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+
+pub struct OverdueConfiguration {
+    pub check_interval: Duration,
+    pub grace_period: Duration,
+}
+
+pub struct AutoAdvanceConfiguration {
+    pub enabled: bool,
+    pub check_interval: Duration,
+}
+
+pub struct Scheduler;
+
+impl Scheduler {
+    // A job that is already running is allowed to finish once shutdown is triggered.
+    pub fn spawn<F, Fut>(
+        name: &'static str,
+        every: Duration,
+        shutdown: Shutdown,
+        job: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<usize, ApplicationError>> + Send,
+    {
+        info!("Starting {} job every {:?}", name, every);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = shutdown.triggered() => break,
+                    _ = interval.tick() => {}
+                }
+                if let Err(e) = job().await {
+                    error!("Failed to run {} job. Error: {:?}", name, e);
+                }
+            }
+
+            info!("Stopped {} job", name);
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::watch;
+use tracing::info;
+
+// Cloned into every listener and background task, which stop once it is triggered.
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            sender: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    pub async fn triggered(&self) {
+        let mut receiver = self.sender.subscribe();
+        // The sender lives as long as self, so waiting can not fail.
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+
+    pub async fn on_signal(&self) -> std::io::Result<()> {
+        let mut terminate = signal(SignalKind::terminate())?;
+        let mut interrupt = signal(SignalKind::interrupt())?;
+
+        tokio::select! {
+            _ = terminate.recv() => info!("Received SIGTERM"),
+            _ = interrupt.recv() => info!("Received SIGINT"),
+            _ = self.triggered() => return Ok(()),
+        }
+
+        self.trigger();
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::server::middleware::TraceMiddleware;
+use opentelemetry::trace::{TraceError, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::runtime::Tokio;
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::Resource;
+use std::str::FromStr;
+use tracing::warn;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct TelemetryConfiguration {
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+}
+
+impl Default for TelemetryConfiguration {
+    fn default() -> Self {
+        Self {
+            otlp_endpoint: None,
+            service_name: env!("CARGO_PKG_NAME").to_string(),
+        }
+    }
+}
+
+// Spans are always logged, and additionally exported over OTLP/HTTP when an endpoint is set.
+pub struct Telemetry {
+    provider: Option<TracerProvider>,
+}
+
+impl Telemetry {
+    pub fn init(
+        filter: &str,
+        format: LogFormat,
+        configuration: TelemetryConfiguration,
+    ) -> Result<Self, TraceError> {
+        let provider = configuration
+            .otlp_endpoint
+            .map(|endpoint| Self::provider(&endpoint, configuration.service_name))
+            .transpose()?;
+        let exporter = provider.as_ref().map(|provider| {
+            tracing_opentelemetry::layer().with_tracer(provider.tracer(env!("CARGO_CRATE_NAME")))
+        });
+
+        // JSON events carry the fields of all enclosing spans, including the request ones.
+        let (text, json) = match format {
+            LogFormat::Text => (Some(tracing_subscriber::fmt::layer()), None),
+            LogFormat::Json => (None, Some(tracing_subscriber::fmt::layer().json())),
+        };
+
+        tracing_subscriber::registry()
+            .with(TraceMiddleware::filter(filter))
+            .with(text)
+            .with(json)
+            .with(exporter)
+            .init();
+
+        Ok(Telemetry { provider })
+    }
+
+    pub fn provider(endpoint: &str, service_name: String) -> Result<TracerProvider, TraceError> {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+            .build()?;
+
+        Ok(TracerProvider::builder()
+            .with_batch_exporter(exporter, Tokio)
+            .with_resource(Resource::new([KeyValue::new("service.name", service_name)]))
+            .build())
+    }
+
+    // Flushes the spans that are still buffered.
+    pub fn shutdown(self) {
+        if let Some(provider) = self.provider {
+            if let Err(e) = provider.shutdown() {
+                warn!("Failed to shut down trace export. Error: {}", e);
+            }
+        }
+    }
+}
@@ -1,63 +1,290 @@
+use crate::application::auto_advance_service::{AutoAdvanceService, AutoAdvanceServiceImpl};
 use crate::application::item_service::ItemServiceImpl;
+use crate::application::overdue_service::{OverdueService, OverdueServiceImpl};
+use crate::domain::clock::SystemClock;
+use crate::domain::item::OverduePolicy;
 use crate::domain::item_factory::ItemFactoryImpl;
-use crate::infrastructure::connection_factory::{
-    DatabaseConfiguration, PostgresConnectionPoolFactory,
+use crate::domain::preparation_estimator::CapacityPreparationTimeEstimator;
+use crate::infrastructure::connection_factory::{ConnectionError, PostgresConnectionPoolFactory};
+use crate::infrastructure::event_publisher::{BroadcastEventPublisher, EventLogSink};
+use crate::infrastructure::health_check::{
+    CircuitBreakerHealthCheck, DatabaseHealthCheck, MigrationHealthCheck,
 };
 use crate::infrastructure::item_repository::ItemRepositoryImpl;
-use crate::server::configuration::Load;
-use crate::server::middleware::{RequestIdMiddleware, TraceMiddleware};
+use crate::infrastructure::metrics::PrometheusMetrics;
+use crate::infrastructure::resilient_repository::{CircuitBreaker, ResilientItemRepository};
+use crate::server::admin::{AdminRouter, HealthProbe};
+use crate::server::configuration::{
+    AppConfiguration, CommandLine, ConfigurationError, EstimationStrategy,
+};
+use crate::server::listener::UnixSocketListener;
+use crate::server::middleware::{
+    LoadSheddingMiddleware, MetricsMiddleware, ProblemDetailsMiddleware, RequestIdMiddleware,
+    TimeoutMiddleware, TraceMiddleware,
+};
+use crate::server::scheduler::Scheduler;
+use crate::server::shutdown::Shutdown;
+use crate::server::telemetry::Telemetry;
 use crate::web::item_endpoint::ItemRouter;
-use axum::http::StatusCode;
-use axum::routing::get;
+use crate::web::overdue_endpoint::OverdueRouter;
+use axum::extract::DefaultBodyLimit;
+use axum::middleware::{from_fn, from_fn_with_state};
 use axum::Router;
+use opentelemetry::trace::TraceError;
 use std::sync::Arc;
+use thiserror::Error;
 use tokio::net::TcpListener;
-use tracing::info;
-use tracing_subscriber::layer::SubscriberExt;
-use tracing_subscriber::util::SubscriberInitExt;
+use tokio::sync::Semaphore;
+use tokio::task::{JoinError, JoinHandle, JoinSet};
+use tracing::{error, info, warn};
+
+#[derive(Debug, Error)]
+pub enum StartupError {
+    #[error("{0}")]
+    Configuration(#[from] ConfigurationError),
+    #[error("Failed to set up trace export. Error: {0}")]
+    Telemetry(#[from] TraceError),
+    #[error("{0}")]
+    Database(#[from] ConnectionError),
+    #[error("Failed to bind to {address}. Error: {source}")]
+    Bind {
+        address: String,
+        source: std::io::Error,
+    },
+    #[error("Server failed. Error: {0}")]
+    Serve(std::io::Error),
+}
 
 pub struct WebServer;
 
 impl WebServer {
-    pub async fn run() {
-        tracing_subscriber::registry()
-            .with(TraceMiddleware::filter())
-            .with(tracing_subscriber::fmt::layer())
-            .init();
+    pub async fn run(command_line: CommandLine) -> Result<(), StartupError> {
+        // Logging is configured too, so it can only be initialised once configuration is loaded.
+        let configuration = AppConfiguration::load(command_line)?;
+        let AppConfiguration {
+            server,
+            logging,
+            request_id,
+            limits,
+            telemetry,
+            database,
+            resilience,
+            estimation,
+            menu,
+            overdue,
+            auto_advance,
+        } = configuration;
+
+        let telemetry = Telemetry::init(&logging.filter, logging.format, telemetry)?;
 
         info!("Application starting");
-        info!("Loading configuration");
-        let config = DatabaseConfiguration::load();
 
         info!("Creating database connection pool and running migrations");
-        let pool = PostgresConnectionPoolFactory::create(config).await;
+        let pool = PostgresConnectionPoolFactory::create(database).await?;
 
         info!("Creating repository");
-        let repository = Arc::new(ItemRepositoryImpl::new(pool));
+        let breaker = Arc::new(CircuitBreaker::new(
+            resilience.failure_threshold,
+            resilience.open_duration,
+        ));
+        let metrics = Arc::new(
+            PrometheusMetrics::new()
+                .with_pool(pool.clone())
+                .with_circuit_breaker(breaker.clone()),
+        );
+        let repository = Arc::new(
+            ResilientItemRepository::new(
+                Arc::new(ItemRepositoryImpl::new(pool.clone()).with_metrics(metrics.clone())),
+                breaker.clone(),
+                resilience,
+            )
+            .with_metrics(metrics.clone()),
+        );
 
         info!("Creating item factory");
-        let factory = Arc::new(ItemFactoryImpl);
+        let factory = Arc::new(ItemFactoryImpl::new(menu));
 
         info!("Creating service");
-        let application = Arc::new(ItemServiceImpl::new(repository, factory));
+        let service = ItemServiceImpl::new(repository.clone(), factory)
+            .with_capacities(estimation.capacities.clone())
+            .with_metrics(metrics.clone())
+            .with_payload_logging(logging.payload)
+            .with_max_items(limits.max_items_per_create);
+        let application = match estimation.strategy {
+            EstimationStrategy::Random => Arc::new(service),
+            EstimationStrategy::Capacity => {
+                info!("Using kitchen capacity preparation time estimation");
+                let estimator = CapacityPreparationTimeEstimator::new(
+                    repository.clone(),
+                    estimation.capacities,
+                );
+                Arc::new(service.with_estimator(Arc::new(estimator)))
+            }
+        };
+
+        let shutdown = Shutdown::new();
+        let mut jobs = Vec::new();
+
+        info!("Creating event publisher");
+        let publisher = Arc::new(BroadcastEventPublisher::new(1024));
+        EventLogSink::spawn(publisher.subscribe());
+
+        info!("Creating overdue service");
+        let overdue_policy = OverduePolicy::new(overdue.grace_period);
+        let overdue_service = Arc::new(OverdueServiceImpl::new(
+            repository.clone(),
+            publisher,
+            overdue_policy,
+        ));
+        let job_service = overdue_service.clone();
+        jobs.push(Scheduler::spawn(
+            "overdue",
+            overdue.check_interval,
+            shutdown.clone(),
+            move || {
+                let service = job_service.clone();
+                async move { service.notify_overdue_items().await }
+            },
+        ));
+
+        if auto_advance.enabled {
+            info!("Creating auto advance service");
+            let auto_advance_service = Arc::new(AutoAdvanceServiceImpl::new(
+                repository.clone(),
+                Arc::new(SystemClock),
+            ));
+            jobs.push(Scheduler::spawn(
+                "auto advance",
+                auto_advance.check_interval,
+                shutdown.clone(),
+                move || {
+                    let service = auto_advance_service.clone();
+                    async move { service.advance_ready_items().await.map(|items| items.len()) }
+                },
+            ));
+        }
 
         info!("Creating router");
-        let router = ItemRouter::create(application.clone());
+        let router = ItemRouter::create(application.clone(), overdue_policy)
+            .merge(OverdueRouter::create(overdue_service, overdue_policy));
 
-        let app = Router::new()
+        // Limits are layered before the admin routes are merged, so they only apply to the API.
+        let permits = Arc::new(Semaphore::new(limits.max_concurrent_requests));
+        let max_body_bytes = limits.max_body_bytes;
+        let mut app = Router::new()
             .nest("/v1/", router)
-            .route("/health", get(|| async { StatusCode::OK }))
-            .layer(TraceMiddleware::create())
-            .layer(RequestIdMiddleware::create());
+            .layer(from_fn_with_state(
+                Arc::new(limits),
+                TimeoutMiddleware::enforce,
+            ))
+            .layer(from_fn_with_state(permits, LoadSheddingMiddleware::shed))
+            .layer(DefaultBodyLimit::max(max_body_bytes));
+        let health = HealthProbe::new(shutdown.clone(), server.health_check_timeout)
+            .with_check(Arc::new(DatabaseHealthCheck::new(pool.clone())))
+            .with_check(Arc::new(MigrationHealthCheck::new(pool.clone())))
+            .with_check(Arc::new(CircuitBreakerHealthCheck::new(breaker)));
+        let admin = AdminRouter::create(health, metrics.clone());
+        if server.admin_address.is_none() {
+            app = app.merge(admin.clone());
+        }
+        let app = ItemRouter::with_custom_methods(
+            app.layer(from_fn(ProblemDetailsMiddleware::negotiate))
+                .layer(from_fn_with_state(metrics, MetricsMiddleware::track))
+                .layer(TraceMiddleware::create())
+                .layer(from_fn_with_state(
+                    Arc::new(request_id),
+                    RequestIdMiddleware::handle,
+                )),
+        );
 
         info!("Binding application server");
-        let listener = TcpListener::bind("0.0.0.0:3000")
+        let address = server.bind_address;
+        let listener = TcpListener::bind(address)
             .await
-            .expect("Failed to bind to port 3000");
+            .map_err(|source| StartupError::Bind {
+                address: address.to_string(),
+                source,
+            })?;
+        info!("Listening on {}", address);
+        let mut servers = JoinSet::new();
+        let public = app.clone();
+        let signal = shutdown.clone();
+        servers.spawn(async move {
+            axum::serve(listener, public)
+                .with_graceful_shutdown(async move { signal.triggered().await })
+                .await
+        });
+
+        if let Some(path) = server.unix_socket {
+            let listener =
+                UnixSocketListener::bind(&path).map_err(|source| StartupError::Bind {
+                    address: path.display().to_string(),
+                    source,
+                })?;
+            info!("Listening on {}", path.display());
+            servers.spawn(UnixSocketListener::serve(listener, app, shutdown.clone()));
+        }
+
+        // The admin listener keeps answering, with failing health, until the others are drained.
+        let mut admin_server = None;
+        if let Some(address) = server.admin_address {
+            let listener =
+                TcpListener::bind(address)
+                    .await
+                    .map_err(|source| StartupError::Bind {
+                        address: address.to_string(),
+                        source,
+                    })?;
+            info!("Admin listening on {}", address);
+            let admin = admin.layer(TraceMiddleware::create());
+            admin_server = Some(tokio::spawn(
+                async move { axum::serve(listener, admin).await },
+            ));
+        }
 
         info!("Starting application server");
-        axum::serve(listener, app)
-            .await
-            .expect("Failed to start server");
+        let result = tokio::select! {
+            signal = shutdown.on_signal() => signal.map_err(StartupError::Serve),
+            Some(stopped) = servers.join_next() => Self::stopped(stopped),
+        };
+        // A failing listener stops the whole server, so the others are drained as well.
+        shutdown.trigger();
+
+        info!(
+            "Shutting down, draining in-flight requests for up to {:?}",
+            server.drain_timeout
+        );
+        let drained = tokio::time::timeout(server.drain_timeout, async {
+            while let Some(stopped) = servers.join_next().await {
+                if let Err(e) = Self::stopped(stopped) {
+                    error!("Listener failed while draining. Error: {}", e);
+                }
+            }
+            for job in jobs.iter_mut() {
+                let _ = job.await;
+            }
+        })
+        .await;
+        if drained.is_err() {
+            warn!("Drain timeout elapsed, aborting remaining requests and jobs");
+            servers.abort_all();
+            jobs.iter().for_each(JoinHandle::abort);
+        }
+        if let Some(admin_server) = admin_server {
+            admin_server.abort();
+        }
+
+        info!("Closing database connection pool");
+        pool.close().await;
+
+        info!("Application stopped");
+        telemetry.shutdown();
+        result
+    }
+
+    fn stopped(result: Result<std::io::Result<()>, JoinError>) -> Result<(), StartupError> {
+        result
+            .map_err(|e| StartupError::Serve(std::io::Error::other(e)))?
+            .map_err(StartupError::Serve)
     }
 }
@@ -1,16 +1,31 @@
+use crate::application::error_code::{ErrorCode, Violation};
 use crate::application::item_service::ApplicationError;
-use axum::extract::rejection::{JsonRejection, PathRejection};
-use axum::http::StatusCode;
+use crate::web::request_id::RequestId;
+use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::Serialize;
 use tracing::warn;
 
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+// Clients are asked to wait this long before retrying a request that failed because the database
+// was unavailable.
+pub const RETRY_AFTER_SECONDS: u64 = 5;
+
 pub enum ServerError {
     InternalServerError,
-    UnprocessableEntity(String),
-    BadRequest(String),
+    ServiceUnavailable,
+    Overloaded,
+    GatewayTimeout,
+    PayloadTooLarge,
+    UnprocessableEntity(ErrorCode, String),
+    BadRequest(ErrorCode, String),
+    Invalid(Vec<Violation>),
     NotFound,
+    Conflict(String),
 }
 
 impl IntoResponse for ServerError {
@@ -18,45 +33,95 @@ impl IntoResponse for ServerError {
         let (status, body) = match self {
             ServerError::InternalServerError => (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                ErrorResponse {
-                    message: "Internal server".to_string(),
-                },
+                ErrorResponse::new(ErrorCode::InternalError, "Internal server error."),
             ),
-            ServerError::UnprocessableEntity(e) => (
-                StatusCode::UNPROCESSABLE_ENTITY,
-                ErrorResponse {
-                    message: e.to_string(),
-                },
+            ServerError::ServiceUnavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ErrorResponse::new(
+                    ErrorCode::ServiceUnavailable,
+                    "Service is temporarily unavailable.",
+                ),
+            ),
+            ServerError::Overloaded => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ErrorResponse::new(ErrorCode::Overloaded, "Server is overloaded."),
+            ),
+            ServerError::GatewayTimeout => (
+                StatusCode::GATEWAY_TIMEOUT,
+                ErrorResponse::new(ErrorCode::RequestTimeout, "Request timed out."),
             ),
-            ServerError::BadRequest(e) => (
-                StatusCode::BAD_REQUEST,
-                ErrorResponse {
-                    message: e.to_string(),
-                },
+            ServerError::PayloadTooLarge => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                ErrorResponse::new(ErrorCode::PayloadTooLarge, "Request body is too large."),
             ),
+            ServerError::UnprocessableEntity(code, e) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(code, e),
+            ),
+            ServerError::BadRequest(code, e) => {
+                (StatusCode::BAD_REQUEST, ErrorResponse::new(code, e))
+            }
+            ServerError::Invalid(violations) => {
+                (StatusCode::BAD_REQUEST, ErrorResponse::invalid(violations))
+            }
             ServerError::NotFound => (
                 StatusCode::NOT_FOUND,
-                ErrorResponse {
-                    message: "Resource not found".to_string(),
-                },
+                ErrorResponse::new(ErrorCode::NotFound, "Resource not found"),
+            ),
+            ServerError::Conflict(e) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(ErrorCode::Conflict, e),
             ),
         };
 
-        (status, Json(body)).into_response()
+        // The problem document is rendered instead of the body when the client asks for it, see
+        // ProblemDetailsMiddleware. The code is kept for MetricsMiddleware.
+        let code = body.code;
+        let problem = ProblemDetails::new(status, &body);
+        let mut response = (status, Json(body)).into_response();
+        if status == StatusCode::SERVICE_UNAVAILABLE {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECONDS));
+        }
+        response.extensions_mut().insert(problem);
+        response.extensions_mut().insert(code);
+        response
     }
 }
 
 impl From<JsonRejection> for ServerError {
     fn from(error: JsonRejection) -> Self {
         warn!("Request body rejected due: {}", error.to_string());
-        ServerError::UnprocessableEntity("Failed to deserialize the JSON body.".to_string())
+        if error.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            return ServerError::PayloadTooLarge;
+        }
+        let code = match error {
+            JsonRejection::JsonSyntaxError(_) => ErrorCode::MalformedJson,
+            JsonRejection::MissingJsonContentType(_) => ErrorCode::MissingContentType,
+            _ => ErrorCode::InvalidBody,
+        };
+        ServerError::UnprocessableEntity(code, "Failed to deserialize the JSON body.".to_string())
     }
 }
 
 impl From<PathRejection> for ServerError {
     fn from(error: PathRejection) -> Self {
         warn!("Request path rejected due: {}", error.to_string());
-        ServerError::UnprocessableEntity("Failed to extract the path parameter.".to_string())
+        ServerError::UnprocessableEntity(
+            ErrorCode::InvalidPath,
+            "Failed to extract the path parameter.".to_string(),
+        )
+    }
+}
+
+impl From<QueryRejection> for ServerError {
+    fn from(error: QueryRejection) -> Self {
+        warn!("Request query rejected due: {}", error.to_string());
+        ServerError::UnprocessableEntity(
+            ErrorCode::InvalidQuery,
+            "Failed to deserialize the query parameters.".to_string(),
+        )
     }
 }
 
@@ -64,13 +129,111 @@ impl From<ApplicationError> for ServerError {
     fn from(error: ApplicationError) -> Self {
         match error {
             ApplicationError::InternalError => ServerError::InternalServerError,
-            ApplicationError::ValidationError(e) => ServerError::BadRequest(e),
+            ApplicationError::Unavailable => ServerError::ServiceUnavailable,
+            ApplicationError::ValidationError(violations) => ServerError::Invalid(violations),
             ApplicationError::ResourceNotFound => ServerError::NotFound,
+            ApplicationError::Conflict(e) => ServerError::Conflict(e),
         }
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ErrorResponse {
+    pub code: ErrorCode,
     pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<ViolationResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl ErrorResponse {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            violations: Vec::new(),
+            request_id: RequestId::current(),
+        }
+    }
+
+    // A single violation keeps its own code, so clients can branch on it without looking into
+    // the list.
+    fn invalid(violations: Vec<Violation>) -> Self {
+        let code = match violations.as_slice() {
+            [violation] => violation.code,
+            _ => ErrorCode::ValidationFailed,
+        };
+        let message = violations
+            .iter()
+            .map(|violation| violation.message.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self {
+            violations: violations
+                .into_iter()
+                .map(ViolationResponse::from)
+                .collect(),
+            ..Self::new(code, message)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ViolationResponse {
+    pub code: ErrorCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl From<Violation> for ViolationResponse {
+    fn from(violation: Violation) -> Self {
+        ViolationResponse {
+            code: violation.code,
+            field: violation.field,
+            message: violation.message,
+        }
+    }
+}
+
+// RFC 7807 problem document. The type is a URN derived from the error code, as the codes are not
+// documented under any URL.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    pub code: ErrorCode,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<ViolationResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, error: &ErrorResponse) -> Self {
+        Self {
+            problem_type: format!("urn:paidy:error:{}", error.code),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: error.message.clone(),
+            instance: None,
+            code: error.code,
+            violations: error.violations.clone(),
+            request_id: error.request_id.clone(),
+        }
+    }
+
+    pub fn with_instance(self, instance: impl Into<String>) -> Self {
+        Self {
+            instance: Some(instance.into()),
+            ..self
+        }
+    }
 }
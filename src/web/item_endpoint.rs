@@ -1,61 +1,208 @@
-use crate::application::item_service::{CreateItemsCommand, ItemModel, ItemService};
+use crate::application::item_service::{
+    ApplicationError, BatchCreateItemsCommand, CreateItemsCommand, ItemService, UpdateItemCommand,
+};
+use crate::domain::item::OverduePolicy;
+use crate::domain::item_query::ItemQuery;
+use crate::domain::station::Station;
 use crate::web::errors::ServerError;
-use crate::web::response::{CreateItemsResponse, ListItemsResponse};
+use crate::web::pagination::{check_parameters, ListItemsQuery};
+use crate::web::representation::{Representation, RepresentationQuery};
+use crate::web::response::{
+    BatchCreateItemsResponse, CreateItemsResponse, ItemResponse, ListItemsResponse, TimingQuery,
+};
 use axum::extract::Json;
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
-use axum::routing::{delete, get, post};
+use axum::extract::{FromRef, Path, Query, Request, State};
+use axum::http::{StatusCode, Uri};
+use axum::routing::{delete, get, patch, post};
 use axum::Router;
 use axum_extra::extract::WithRejection;
+use serde::Deserialize;
 use std::sync::Arc;
+use tower::ServiceExt;
 use uuid::Uuid;
 
 pub struct ItemRouter;
 
+#[derive(Clone)]
+struct ItemRouterState {
+    service: Arc<dyn ItemService + Send + Sync>,
+    overdue: OverduePolicy,
+}
+
+impl FromRef<ItemRouterState> for Arc<dyn ItemService + Send + Sync> {
+    fn from_ref(state: &ItemRouterState) -> Self {
+        state.service.clone()
+    }
+}
+
+impl FromRef<ItemRouterState> for OverduePolicy {
+    fn from_ref(state: &ItemRouterState) -> Self {
+        state.overdue
+    }
+}
+
 impl ItemRouter {
-    pub fn create(service: Arc<dyn ItemService + Send + Sync>) -> Router {
+    pub fn create(service: Arc<dyn ItemService + Send + Sync>, overdue: OverduePolicy) -> Router {
         Router::new()
             .route("/tables/:table_id/items", post(create_items))
+            .route("/items%3Abatch", post(create_batch))
             .route("/tables/:table_id/items", get(list_items))
             .route("/tables/:table_id/items/:item_id", get(get_item))
+            .route("/tables/:table_id/items/:item_id", patch(update_item))
             .route("/tables/:table_id/items/:item_id", delete(delete_item))
-            .with_state(service)
+            .route("/stations/:station/items", get(list_station_items))
+            .with_state(ItemRouterState { service, overdue })
     }
+
+    // The router treats `:` as the start of a path parameter, so custom methods such as
+    // `/items:batch` are routed on their percent-encoded form. This has to wrap the whole
+    // application, since nested routers only see the path after their prefix.
+    pub fn with_custom_methods(router: Router) -> Router {
+        Router::new().fallback_service(router.map_request(encode_custom_methods))
+    }
+}
+
+fn encode_custom_methods(mut request: Request) -> Request {
+    let uri = request.uri();
+    if !uri.path().contains(':') {
+        return request;
+    }
+
+    let path = uri.path().replace(':', "%3A");
+    let path_and_query = match uri.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path,
+    };
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = path_and_query.parse().ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *request.uri_mut() = uri;
+    }
+    request
 }
 
 async fn create_items(
     State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
     WithRejection(Path(table_id), _): WithRejection<Path<i64>, ServerError>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
     WithRejection(Json(command), _): WithRejection<Json<CreateItemsCommand>, ServerError>,
 ) -> Result<(StatusCode, Json<CreateItemsResponse>), ServerError> {
     let items = service.create_items(table_id, command).await?;
 
-    Ok((StatusCode::CREATED, Json(CreateItemsResponse::from(items))))
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateItemsResponse::new(items, query.timing(overdue))),
+    ))
+}
+
+async fn create_batch(
+    State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
+    WithRejection(Json(command), _): WithRejection<Json<BatchCreateItemsCommand>, ServerError>,
+) -> Result<(StatusCode, Json<BatchCreateItemsResponse>), ServerError> {
+    let batch = service.create_batch(command).await?;
+    let status = if batch.errors.is_empty() {
+        StatusCode::CREATED
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+
+    Ok((
+        status,
+        Json(BatchCreateItemsResponse::new(batch, query.timing(overdue))),
+    ))
 }
 
 async fn list_items(
     State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
     WithRejection(Path(table_id), _): WithRejection<Path<i64>, ServerError>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
+    WithRejection(Query(list_query), _): WithRejection<Query<ListItemsQuery>, ServerError>,
+    WithRejection(Query(representation), _): WithRejection<Query<RepresentationQuery>, ServerError>,
+    WithRejection(Query(parameters), _): WithRejection<Query<Vec<(String, String)>>, ServerError>,
+) -> Result<(StatusCode, Json<ListItemsResponse>), ServerError> {
+    let known = [
+        TimingQuery::PARAMETERS.as_slice(),
+        ListItemsQuery::PARAMETERS.as_slice(),
+        RepresentationQuery::PARAMETERS.as_slice(),
+    ]
+    .concat();
+    check_parameters(&parameters, &known)?;
+    let representation = Representation::try_from(representation)?;
+    let page = service
+        .get_items(table_id, ItemQuery::try_from(list_query)?)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ListItemsResponse::from_page(
+            page,
+            query.timing(overdue),
+            &representation,
+        )),
+    ))
+}
+
+async fn list_station_items(
+    State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
+    WithRejection(Path(station), _): WithRejection<Path<Station>, ServerError>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
 ) -> Result<(StatusCode, Json<ListItemsResponse>), ServerError> {
-    let items = service.get_items(table_id).await?;
+    let items = service.get_station_items(station).await?;
 
-    Ok((StatusCode::OK, Json(ListItemsResponse::from(items))))
+    Ok((
+        StatusCode::OK,
+        Json(ListItemsResponse::new(items, query.timing(overdue))),
+    ))
 }
 
 async fn get_item(
     State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
     WithRejection(Path((table_id, item_id)), _): WithRejection<Path<(i64, Uuid)>, ServerError>,
-) -> Result<(StatusCode, Json<ItemModel>), ServerError> {
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
+) -> Result<(StatusCode, Json<ItemResponse>), ServerError> {
     let item = service.get_item(table_id, item_id).await?;
 
-    Ok((StatusCode::OK, Json(item)))
+    Ok((
+        StatusCode::OK,
+        Json(ItemResponse::new(item, query.timing(overdue))),
+    ))
+}
+
+async fn update_item(
+    State(service): State<Arc<dyn ItemService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
+    WithRejection(Path((table_id, item_id)), _): WithRejection<Path<(i64, Uuid)>, ServerError>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
+    WithRejection(Json(command), _): WithRejection<Json<UpdateItemCommand>, ServerError>,
+) -> Result<(StatusCode, Json<ItemResponse>), ServerError> {
+    let item = service.update_item(table_id, item_id, command).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ItemResponse::new(item, query.timing(overdue))),
+    ))
 }
 
 async fn delete_item(
     State(service): State<Arc<dyn ItemService + Send + Sync>>,
     WithRejection(Path((table_id, item_id)), _): WithRejection<Path<(i64, Uuid)>, ServerError>,
+    WithRejection(Query(query), _): WithRejection<Query<DeleteItemQuery>, ServerError>,
 ) -> Result<StatusCode, ServerError> {
-    service.delete_item(table_id, item_id).await?;
+    match service.delete_item(table_id, item_id).await {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(ApplicationError::ResourceNotFound) if query.idempotent => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err(e.into()),
+    }
+}
 
-    Ok(StatusCode::NO_CONTENT)
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteItemQuery {
+    #[serde(default)]
+    pub idempotent: bool,
 }
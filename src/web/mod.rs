@@ -1,3 +1,7 @@
 pub mod errors;
 pub mod item_endpoint;
+pub mod overdue_endpoint;
+pub mod pagination;
+pub mod representation;
+pub mod request_id;
 pub mod response;
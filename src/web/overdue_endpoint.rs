@@ -0,0 +1,54 @@
+use crate::application::overdue_service::OverdueService;
+use crate::domain::item::OverduePolicy;
+use crate::web::errors::ServerError;
+use crate::web::response::{ListItemsResponse, TimingQuery};
+use axum::extract::{FromRef, Json, Query, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use axum_extra::extract::WithRejection;
+use std::sync::Arc;
+
+pub struct OverdueRouter;
+
+#[derive(Clone)]
+struct OverdueRouterState {
+    service: Arc<dyn OverdueService + Send + Sync>,
+    overdue: OverduePolicy,
+}
+
+impl FromRef<OverdueRouterState> for Arc<dyn OverdueService + Send + Sync> {
+    fn from_ref(state: &OverdueRouterState) -> Self {
+        state.service.clone()
+    }
+}
+
+impl FromRef<OverdueRouterState> for OverduePolicy {
+    fn from_ref(state: &OverdueRouterState) -> Self {
+        state.overdue
+    }
+}
+
+impl OverdueRouter {
+    pub fn create(
+        service: Arc<dyn OverdueService + Send + Sync>,
+        overdue: OverduePolicy,
+    ) -> Router {
+        Router::new()
+            .route("/items/overdue", get(list_overdue_items))
+            .with_state(OverdueRouterState { service, overdue })
+    }
+}
+
+async fn list_overdue_items(
+    State(service): State<Arc<dyn OverdueService + Send + Sync>>,
+    State(overdue): State<OverduePolicy>,
+    WithRejection(Query(query), _): WithRejection<Query<TimingQuery>, ServerError>,
+) -> Result<(StatusCode, Json<ListItemsResponse>), ServerError> {
+    let items = service.get_overdue_items().await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ListItemsResponse::new(items, query.timing(overdue))),
+    ))
+}
@@ -0,0 +1,155 @@
+use crate::application::error_code::ErrorCode;
+use crate::domain::item_query::{ItemCursor, ItemQuery, SortOrder, DEFAULT_PAGE_SIZE};
+use crate::web::errors::ServerError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::warn;
+use uuid::Uuid;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ListItemsQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub name: Option<String>,
+    pub prepared_after: Option<DateTime<Utc>>,
+    pub prepared_before: Option<DateTime<Utc>>,
+    pub order: Option<SortOrder>,
+}
+
+impl ListItemsQuery {
+    pub const PARAMETERS: [&'static str; 6] = [
+        "cursor",
+        "limit",
+        "name",
+        "prepared_after",
+        "prepared_before",
+        "order",
+    ];
+}
+
+// A listing reads its parameters through several extractors, so none of them can deny unknown
+// fields on its own. A misspelled parameter would otherwise silently return an unfiltered page.
+pub fn check_parameters(
+    parameters: &[(String, String)],
+    known: &[&str],
+) -> Result<(), ServerError> {
+    let mut unknown = Vec::new();
+    for (name, _) in parameters {
+        if !known.contains(&name.as_str()) && !unknown.contains(&name.as_str()) {
+            unknown.push(name.as_str());
+        }
+    }
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    warn!("Rejected unknown query parameters: {:?}", unknown);
+    Err(ServerError::BadRequest(
+        ErrorCode::UnknownParameter,
+        format!(
+            "Unknown query parameter: {}. Known parameters are: {}.",
+            unknown.join(", "),
+            known.join(", ")
+        ),
+    ))
+}
+
+impl TryFrom<ListItemsQuery> for ItemQuery {
+    type Error = ServerError;
+
+    fn try_from(query: ListItemsQuery) -> Result<Self, ServerError> {
+        let after = query.cursor.as_deref().map(decode_cursor).transpose()?;
+
+        Ok(ItemQuery {
+            name: query.name.filter(|name| !name.is_empty()),
+            prepared_after: query.prepared_after,
+            prepared_before: query.prepared_before,
+            order: query.order.unwrap_or_default(),
+            after,
+            limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        })
+    }
+}
+
+pub fn encode_cursor(cursor: &ItemCursor) -> String {
+    let raw = format!(
+        "{}|{}",
+        cursor.preparation_time.timestamp_micros(),
+        cursor.id
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+pub fn decode_cursor(cursor: &str) -> Result<ItemCursor, ServerError> {
+    let invalid = || {
+        warn!("Rejected invalid cursor: {}", cursor);
+        ServerError::BadRequest(ErrorCode::InvalidCursor, "Invalid cursor.".to_string())
+    };
+
+    let raw = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+    let (micros, id) = raw.split_once('|').ok_or_else(invalid)?;
+
+    let preparation_time = micros
+        .parse()
+        .ok()
+        .and_then(DateTime::from_timestamp_micros)
+        .ok_or_else(invalid)?;
+    let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+
+    Ok(ItemCursor {
+        preparation_time,
+        id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::SubsecRound;
+
+    #[test]
+    fn should_round_trip_cursor() {
+        let cursor = ItemCursor {
+            preparation_time: Utc::now().round_subsecs(6),
+            id: Uuid::now_v7(),
+        };
+
+        let decoded = decode_cursor(&encode_cursor(&cursor));
+
+        assert!(matches!(decoded, Ok(value) if value == cursor));
+    }
+
+    #[test]
+    fn should_reject_invalid_cursor() {
+        let decoded = decode_cursor("not-a-cursor");
+
+        assert!(matches!(decoded, Err(ServerError::BadRequest(_, _))));
+    }
+
+    #[test]
+    fn should_reject_unknown_parameters() {
+        let parameters = vec![
+            ("sort_by".to_string(), "name".to_string()),
+            ("limit".to_string(), "10".to_string()),
+            ("sort_by".to_string(), "id".to_string()),
+        ];
+
+        let result = check_parameters(&parameters, &ListItemsQuery::PARAMETERS);
+
+        assert!(matches!(
+            result,
+            Err(ServerError::BadRequest(ErrorCode::UnknownParameter, message))
+                if message.starts_with("Unknown query parameter: sort_by. Known parameters are: cursor, limit")
+        ));
+    }
+
+    #[test]
+    fn should_accept_known_parameters() {
+        let parameters = vec![("order".to_string(), "desc".to_string())];
+
+        assert!(check_parameters(&parameters, &ListItemsQuery::PARAMETERS).is_ok());
+    }
+}
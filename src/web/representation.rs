@@ -0,0 +1,194 @@
+use crate::application::error_code::ErrorCode;
+use crate::web::errors::ServerError;
+use crate::web::response::{ItemResponse, ItemSummary};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemView {
+    Summary,
+    #[default]
+    Full,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ItemField {
+    Id,
+    TableId,
+    Name,
+    PreparationTime,
+    Station,
+    Status,
+    Notes,
+    RemainingSeconds,
+    IsOverdue,
+}
+
+impl ItemField {
+    pub const ALL: [ItemField; 9] = [
+        ItemField::Id,
+        ItemField::TableId,
+        ItemField::Name,
+        ItemField::PreparationTime,
+        ItemField::Station,
+        ItemField::Status,
+        ItemField::Notes,
+        ItemField::RemainingSeconds,
+        ItemField::IsOverdue,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemField::Id => "id",
+            ItemField::TableId => "table_id",
+            ItemField::Name => "name",
+            ItemField::PreparationTime => "preparation_time",
+            ItemField::Station => "station",
+            ItemField::Status => "status",
+            ItemField::Notes => "notes",
+            ItemField::RemainingSeconds => "remaining_seconds",
+            ItemField::IsOverdue => "is_overdue",
+        }
+    }
+}
+
+impl FromStr for ItemField {
+    type Err = ServerError;
+
+    fn from_str(value: &str) -> Result<Self, ServerError> {
+        ItemField::ALL
+            .into_iter()
+            .find(|field| field.as_str() == value)
+            .ok_or_else(|| {
+                let known = ItemField::ALL.map(|field| field.as_str()).join(", ");
+                ServerError::BadRequest(
+                    ErrorCode::UnknownField,
+                    format!("Unknown field: {}. Known fields are: {}.", value, known),
+                )
+            })
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RepresentationQuery {
+    pub view: Option<ItemView>,
+    pub fields: Option<String>,
+}
+
+impl RepresentationQuery {
+    pub const PARAMETERS: [&'static str; 2] = ["view", "fields"];
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Representation {
+    #[default]
+    Full,
+    Summary,
+    Fields(Vec<ItemField>),
+}
+
+impl TryFrom<RepresentationQuery> for Representation {
+    type Error = ServerError;
+
+    fn try_from(query: RepresentationQuery) -> Result<Self, ServerError> {
+        match (query.view, query.fields) {
+            (Some(_), Some(_)) => Err(ServerError::BadRequest(
+                ErrorCode::ConflictingRepresentation,
+                "The view and fields parameters cannot be combined.".to_string(),
+            )),
+            (_, Some(fields)) => {
+                let fields = fields
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|field| !field.is_empty())
+                    .map(ItemField::from_str)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if fields.is_empty() {
+                    return Err(ServerError::BadRequest(
+                        ErrorCode::EmptyFieldSelection,
+                        "At least one field must be selected.".to_string(),
+                    ));
+                }
+
+                Ok(Representation::Fields(fields))
+            }
+            (Some(ItemView::Summary), None) => Ok(Representation::Summary),
+            (Some(ItemView::Full), None) | (None, None) => Ok(Representation::Full),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum ItemRepresentation {
+    Full(ItemResponse),
+    Summary(ItemSummary),
+    Sparse(Map<String, Value>),
+}
+
+impl ItemRepresentation {
+    pub fn new(item: ItemResponse, representation: &Representation) -> Self {
+        match representation {
+            Representation::Full => ItemRepresentation::Full(item),
+            Representation::Summary => ItemRepresentation::Summary(ItemSummary::from(item)),
+            Representation::Fields(fields) => {
+                let mut values = match serde_json::to_value(item) {
+                    Ok(Value::Object(values)) => values,
+                    _ => Map::new(),
+                };
+                values.retain(|key, _| fields.iter().any(|field| field.as_str() == key));
+                ItemRepresentation::Sparse(values)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_fields() {
+        let query = RepresentationQuery {
+            view: None,
+            fields: Some("id, name".to_string()),
+        };
+
+        let representation = Representation::try_from(query);
+
+        assert!(matches!(
+            representation,
+            Ok(Representation::Fields(fields)) if fields == vec![ItemField::Id, ItemField::Name]
+        ));
+    }
+
+    #[test]
+    fn should_reject_unknown_field() {
+        let query = RepresentationQuery {
+            view: None,
+            fields: Some("id,price".to_string()),
+        };
+
+        let representation = Representation::try_from(query);
+
+        assert!(matches!(
+            representation,
+            Err(ServerError::BadRequest(ErrorCode::UnknownField, message)) if message.starts_with("Unknown field: price.")
+        ));
+    }
+
+    #[test]
+    fn should_reject_view_with_fields() {
+        let query = RepresentationQuery {
+            view: Some(ItemView::Summary),
+            fields: Some("id".to_string()),
+        };
+
+        let representation = Representation::try_from(query);
+
+        assert!(matches!(representation, Err(ServerError::BadRequest(_, _))));
+    }
+}
@@ -0,0 +1,20 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+// Set by the request id middleware, both as a request extension and for the duration of the
+// request, so that error responses can carry it without every handler extracting it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    pub fn current() -> Option<String> {
+        CURRENT.try_with(|request_id| request_id.0.clone()).ok()
+    }
+}
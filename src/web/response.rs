@@ -1,6 +1,11 @@
-use crate::application::item_service::ItemModel;
+use crate::application::error_code::ErrorCode;
+use crate::application::item_service::{BatchModel, ItemModel, ItemModelPage};
+use crate::domain::item::{ItemStatus, OverduePolicy};
+use crate::domain::station::Station;
+use crate::web::pagination::encode_cursor;
+use crate::web::representation::{ItemRepresentation, Representation};
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Default, Serialize)]
@@ -9,27 +14,171 @@ pub struct ItemDetailsResponse {
     pub table_id: i64,
     pub name: String,
     pub preparation_time: DateTime<Utc>,
+    pub station: Station,
+    pub status: ItemStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
 }
 
-#[derive(Default, Serialize)]
+impl From<ItemModel> for ItemDetailsResponse {
+    fn from(item: ItemModel) -> Self {
+        ItemDetailsResponse {
+            id: item.id,
+            table_id: item.table_id,
+            name: item.name,
+            preparation_time: item.preparation_time,
+            station: item.station,
+            status: item.status,
+            notes: item.notes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ItemResponse {
+    #[serde(flatten)]
+    pub item: ItemDetailsResponse,
+    #[serde(flatten)]
+    pub timing: Option<ItemTiming>,
+}
+
+impl ItemResponse {
+    pub fn new(item: ItemModel, timing: Option<Timing>) -> Self {
+        let timing =
+            timing.map(|timing| ItemTiming::new(item.preparation_time, item.status, timing));
+        ItemResponse {
+            item: ItemDetailsResponse::from(item),
+            timing,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ItemTiming {
+    pub remaining_seconds: i64,
+    pub is_overdue: bool,
+}
+
+impl ItemTiming {
+    pub fn new(preparation_time: DateTime<Utc>, status: ItemStatus, timing: Timing) -> Self {
+        ItemTiming {
+            remaining_seconds: (preparation_time - timing.now).num_seconds().max(0),
+            is_overdue: timing
+                .overdue
+                .is_overdue(status, preparation_time, timing.now),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    pub now: DateTime<Utc>,
+    pub overdue: OverduePolicy,
+}
+
+#[derive(Serialize)]
 pub struct CreateItemsResponse {
-    pub items: Vec<ItemModel>,
+    pub items: Vec<ItemResponse>,
 }
 
-impl From<Vec<ItemModel>> for CreateItemsResponse {
-    fn from(items: Vec<ItemModel>) -> Self {
+impl CreateItemsResponse {
+    pub fn new(items: Vec<ItemModel>, timing: Option<Timing>) -> Self {
+        let items = items
+            .into_iter()
+            .map(|item| ItemResponse::new(item, timing))
+            .collect();
         CreateItemsResponse { items }
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Serialize)]
+pub struct BatchCreateItemsResponse {
+    pub tables: Vec<TableItemsResponse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<BatchErrorResponse>,
+}
+
+impl BatchCreateItemsResponse {
+    pub fn new(batch: BatchModel, timing: Option<Timing>) -> Self {
+        let tables = batch
+            .tables
+            .into_iter()
+            .map(|table| TableItemsResponse {
+                table_id: table.table_id,
+                items: table
+                    .items
+                    .into_iter()
+                    .map(|item| ItemResponse::new(item, timing))
+                    .collect(),
+            })
+            .collect();
+        let errors = batch
+            .errors
+            .into_iter()
+            .map(|error| BatchErrorResponse {
+                index: error.index,
+                table_id: error.table_id,
+                code: error.code,
+                field: error.field,
+                message: error.message,
+            })
+            .collect();
+
+        BatchCreateItemsResponse { tables, errors }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TableItemsResponse {
+    pub table_id: i64,
+    pub items: Vec<ItemResponse>,
+}
+
+#[derive(Serialize)]
+pub struct BatchErrorResponse {
+    pub index: usize,
+    pub table_id: i64,
+    pub code: ErrorCode,
+    pub field: &'static str,
+    pub message: String,
+}
+
+#[derive(Serialize)]
 pub struct ListItemsResponse {
-    pub items: Vec<ItemModel>,
+    pub items: Vec<ItemRepresentation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
-impl From<Vec<ItemModel>> for ListItemsResponse {
-    fn from(items: Vec<ItemModel>) -> Self {
-        ListItemsResponse { items }
+impl ListItemsResponse {
+    pub fn new(items: Vec<ItemModel>, timing: Option<Timing>) -> Self {
+        Self::with_representation(items, timing, &Representation::Full)
+    }
+
+    pub fn with_representation(
+        items: Vec<ItemModel>,
+        timing: Option<Timing>,
+        representation: &Representation,
+    ) -> Self {
+        let items = items
+            .into_iter()
+            .map(|item| ItemRepresentation::new(ItemResponse::new(item, timing), representation))
+            .collect();
+        ListItemsResponse {
+            items,
+            next_cursor: None,
+        }
+    }
+
+    pub fn from_page(
+        page: ItemModelPage,
+        timing: Option<Timing>,
+        representation: &Representation,
+    ) -> Self {
+        ListItemsResponse {
+            next_cursor: page.next.as_ref().map(encode_cursor),
+            ..Self::with_representation(page.items, timing, representation)
+        }
     }
 }
 
@@ -38,3 +187,107 @@ pub struct ItemSummary {
     pub id: String,
     pub name: String,
 }
+
+impl From<ItemResponse> for ItemSummary {
+    fn from(item: ItemResponse) -> Self {
+        ItemSummary {
+            id: item.item.id.to_string(),
+            name: item.item.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimingQuery {
+    #[serde(default = "TimingQuery::default_timing")]
+    pub timing: bool,
+}
+
+impl TimingQuery {
+    pub const PARAMETERS: [&'static str; 1] = ["timing"];
+
+    fn default_timing() -> bool {
+        true
+    }
+
+    pub fn timing(&self, overdue: OverduePolicy) -> Option<Timing> {
+        self.timing.then(|| Timing {
+            now: Utc::now(),
+            overdue,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use std::time::Duration;
+
+    fn timing(now: DateTime<Utc>) -> Timing {
+        Timing {
+            now,
+            overdue: OverduePolicy::default(),
+        }
+    }
+
+    #[test]
+    fn should_compute_remaining_time() {
+        let now = Utc::now();
+
+        let timing = ItemTiming::new(
+            now + TimeDelta::seconds(90),
+            ItemStatus::Pending,
+            timing(now),
+        );
+
+        assert_eq!(
+            timing,
+            ItemTiming {
+                remaining_seconds: 90,
+                is_overdue: false,
+            }
+        );
+    }
+
+    #[test]
+    fn should_mark_overdue_item() {
+        let now = Utc::now();
+
+        let timing = ItemTiming::new(
+            now - TimeDelta::seconds(90),
+            ItemStatus::Pending,
+            timing(now),
+        );
+
+        assert_eq!(
+            timing,
+            ItemTiming {
+                remaining_seconds: 0,
+                is_overdue: true,
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_mark_ready_item_overdue() {
+        let now = Utc::now();
+
+        let timing = ItemTiming::new(now - TimeDelta::seconds(90), ItemStatus::Ready, timing(now));
+
+        assert!(!timing.is_overdue);
+    }
+
+    #[test]
+    fn should_not_mark_item_within_grace_period_overdue() {
+        let now = Utc::now();
+        let timing = Timing {
+            now,
+            overdue: OverduePolicy::new(Duration::from_secs(120)),
+        };
+
+        let timing = ItemTiming::new(now - TimeDelta::seconds(90), ItemStatus::Pending, timing);
+
+        assert!(!timing.is_overdue);
+    }
+}
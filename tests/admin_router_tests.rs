@@ -0,0 +1,169 @@
+mod admin_router_tests {
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::Router;
+    use paidy_submission::domain::health::{HealthCheck, MockHealthCheck};
+    use paidy_submission::domain::metrics::ItemMetrics;
+    use paidy_submission::infrastructure::metrics::PrometheusMetrics;
+    use paidy_submission::server::admin::{AdminRouter, HealthProbe};
+    use paidy_submission::server::shutdown::Shutdown;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    struct HangingCheck;
+
+    #[async_trait]
+    impl HealthCheck for HangingCheck {
+        fn name(&self) -> &'static str {
+            "hanging"
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        }
+    }
+
+    fn check(name: &'static str, result: Result<(), String>) -> Arc<MockHealthCheck> {
+        let mut check = MockHealthCheck::new();
+        check.expect_name().return_const(name);
+        check.expect_check().returning(move || result.clone());
+        Arc::new(check)
+    }
+
+    fn router(shutdown: Shutdown, database: Result<(), String>) -> Router {
+        AdminRouter::create(
+            HealthProbe::new(shutdown, Duration::from_millis(100))
+                .with_check(check("database", database))
+                .with_check(check("migrations", Ok(()))),
+            Arc::new(PrometheusMetrics::new()),
+        )
+    }
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, Value) {
+        let response = router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn should_report_ready_when_all_checks_pass() {
+        let (status, body) = get(router(Shutdown::new(), Ok(())), "/health/ready").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "up");
+        assert_eq!(body["checks"]["database"]["status"], "up");
+        assert_eq!(body["checks"]["migrations"]["status"], "up");
+        assert!(body["checks"]["database"]["latency_ms"].is_u64());
+        assert!(body["checks"]["database"].get("error").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_report_not_ready_when_check_fails() {
+        let router = router(Shutdown::new(), Err("Connection refused".to_string()));
+
+        let (status, body) = get(router, "/health/ready").await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "down");
+        assert_eq!(body["checks"]["database"]["status"], "down");
+        assert_eq!(body["checks"]["database"]["error"], "Connection refused");
+        assert_eq!(body["checks"]["migrations"]["status"], "up");
+    }
+
+    #[tokio::test]
+    async fn should_time_out_hanging_check() {
+        let router = AdminRouter::create(
+            HealthProbe::new(Shutdown::new(), Duration::from_millis(50))
+                .with_check(Arc::new(HangingCheck)),
+            Arc::new(PrometheusMetrics::new()),
+        );
+
+        let (status, body) =
+            tokio::time::timeout(Duration::from_secs(1), get(router, "/health/ready"))
+                .await
+                .expect("Probe was not bounded");
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["hanging"]["error"], "Timed out after 50 ms");
+    }
+
+    #[tokio::test]
+    async fn should_report_live_without_checking_dependencies() {
+        let mut database = MockHealthCheck::new();
+        database.expect_name().return_const("database");
+        database.expect_check().never();
+        let router = AdminRouter::create(
+            HealthProbe::new(Shutdown::new(), Duration::from_millis(100))
+                .with_check(Arc::new(database)),
+            Arc::new(PrometheusMetrics::new()),
+        );
+
+        let (status, body) = get(router, "/health/live").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({"status": "up", "checks": {}}));
+    }
+
+    #[tokio::test]
+    async fn should_report_not_ready_once_shutdown_is_triggered() {
+        let shutdown = Shutdown::new();
+        let router = router(shutdown.clone(), Ok(()));
+        shutdown.trigger();
+
+        let (ready, body) = get(router.clone(), "/health/ready").await;
+        let (live, _) = get(router, "/health/live").await;
+
+        assert_eq!(ready, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["server"]["error"], "Shutting down");
+        assert_eq!(live, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_serve_readiness_on_legacy_health_path() {
+        let (status, _) = get(router(Shutdown::new(), Ok(())), "/health").await;
+
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_not_serve_api_on_admin_router() {
+        let response = router(Shutdown::new(), Ok(()))
+            .oneshot(Request::get("/v1/items").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn should_expose_metrics_in_prometheus_format() {
+        let metrics = Arc::new(PrometheusMetrics::new());
+        metrics.items_created(1, 2);
+        let router = AdminRouter::create(
+            HealthProbe::new(Shutdown::new(), Duration::from_millis(100)),
+            metrics,
+        );
+
+        let response = router
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let content_type = response.headers()["content-type"].clone();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        assert_eq!(content_type, "text/plain; version=0.0.4; charset=utf-8");
+        assert!(String::from_utf8_lossy(&body).contains("items_created_total{table_id=\"1\"} 2"));
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{Duration, Utc};
+use mockall::predicate::eq;
+use paidy_submission::application::auto_advance_service::{
+    AutoAdvanceService, AutoAdvanceServiceImpl,
+};
+use paidy_submission::application::item_service::ApplicationError;
+use paidy_submission::domain::audit::SYSTEM_ACTOR;
+use paidy_submission::domain::clock::MockClock;
+use paidy_submission::domain::item::{Item, ItemStatus};
+use paidy_submission::domain::repository::{MockItemRepository, RepositoryError};
+use paidy_submission::domain::station::Station;
+use std::sync::Arc;
+use uuid::Uuid;
+
+mod auto_advance_service_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_advance_items_using_clock() {
+        let now = Utc::now();
+        let item = Item::try_new(
+            Uuid::now_v7(),
+            1,
+            "Pierogi".to_string(),
+            now - Duration::minutes(1),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+        let ready = Item {
+            status: ItemStatus::Ready,
+            ..item
+        };
+
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now).once();
+
+        let mut repository = MockItemRepository::new();
+        repository
+            .expect_advance_ready_items()
+            .with(eq(now), eq(SYSTEM_ACTOR))
+            .return_const(Ok(vec![ready.clone()]))
+            .once();
+
+        let service = AutoAdvanceServiceImpl::new(Arc::new(repository), Arc::new(clock));
+
+        let result = service
+            .advance_ready_items()
+            .await
+            .expect("Failed to advance items");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, ready.id);
+        assert_eq!(result[0].status, ItemStatus::Ready);
+    }
+
+    #[tokio::test]
+    async fn should_handle_repository_error() {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now()).once();
+
+        let mut repository = MockItemRepository::new();
+        repository
+            .expect_advance_ready_items()
+            .return_const(Err(RepositoryError::InternalRepositoryError(
+                "Crash".to_string(),
+            )))
+            .once();
+
+        let service = AutoAdvanceServiceImpl::new(Arc::new(repository), Arc::new(clock));
+
+        let result = service
+            .advance_ready_items()
+            .await
+            .expect_err("Advance items did not fail");
+
+        assert_eq!(result, ApplicationError::InternalError);
+    }
+}
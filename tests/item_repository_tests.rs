@@ -3,19 +3,24 @@ use paidy_submission::infrastructure::connection_factory::{
     DatabaseConfiguration, PostgresConnectionPoolFactory,
 };
 use paidy_submission::infrastructure::item_repository::ItemRepositoryImpl;
+use sqlx::PgPool;
 use testcontainers::runners::AsyncRunner;
 use testcontainers::ContainerAsync;
 use testcontainers_modules::postgres::Postgres;
 
 mod repository_tests {
     use super::*;
-    use chrono::Utc;
-    use paidy_submission::domain::item::Item;
+    use chrono::{SubsecRound, Utc};
+    use paidy_submission::domain::audit::SYSTEM_ACTOR;
+    use paidy_submission::domain::item::{Item, ItemStatus};
     use paidy_submission::domain::item_factory::{ItemFactory, ItemFactoryImpl};
+    use paidy_submission::domain::item_query::{ItemQuery, SortOrder};
     use paidy_submission::domain::repository::RepositoryError;
+    use paidy_submission::domain::station::Station;
     use uuid::Uuid;
 
     struct RepositoryTestContext {
+        pool: PgPool,
         repository: ItemRepositoryImpl,
         factory: ItemFactoryImpl,
         _container: ContainerAsync<Postgres>,
@@ -32,21 +37,27 @@ mod repository_tests {
                 .expect("Failed to create PostgreSQL container");
 
             let config = DatabaseConfiguration {
-                host: "localhost".to_string(),
-                port: container
-                    .get_host_port_ipv4(5432)
-                    .await
-                    .expect("Failed to get port"),
-                database: "test".to_string(),
-                username: "root".to_string(),
-                password: "qwerty".to_string(),
+                host: Some("localhost".to_string()),
+                port: Some(
+                    container
+                        .get_host_port_ipv4(5432)
+                        .await
+                        .expect("Failed to get port"),
+                ),
+                database: Some("test".to_string()),
+                username: Some("root".to_string()),
+                password: Some("qwerty".to_string()),
+                ..DatabaseConfiguration::default()
             };
 
-            let pool = PostgresConnectionPoolFactory::create(config).await;
+            let pool = PostgresConnectionPoolFactory::create(config)
+                .await
+                .expect("Failed to create pool");
             let repository = ItemRepositoryImpl::new(pool.clone());
             let factory = ItemFactoryImpl::default();
 
             RepositoryTestContext {
+                pool,
                 repository,
                 factory,
                 _container: container,
@@ -62,36 +73,63 @@ mod repository_tests {
             let context = RepositoryTestContext::create_test_context().await;
             let item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
 
-            context
+            let deleted = context
                 .repository
                 .delete_item(&item.table_id, &item.id)
                 .await
                 .expect("Failed to delete item");
+
+            assert!(!deleted);
         }
 
         #[tokio::test]
-        async fn should_delete_existing_item() {
+        async fn should_not_delete_item_of_other_table() {
             let context = RepositoryTestContext::create_test_context().await;
             let item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
 
             context
                 .repository
-                .save_items(&vec![item.clone()])
+                .save_items(std::slice::from_ref(&item))
                 .await
                 .expect("Failed to save item");
 
+            let deleted = context
+                .repository
+                .delete_item(&2, &item.id)
+                .await
+                .expect("Failed to delete item");
+
+            assert!(!deleted);
+        }
+
+        #[tokio::test]
+        async fn should_delete_existing_item() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let item = context
+                .factory
+                .try_create(1, "Pierogi".to_string(), None)
+                .expect("Failed to create item");
+
             context
+                .repository
+                .save_items(std::slice::from_ref(&item))
+                .await
+                .expect("Failed to save item");
+
+            let deleted = context
                 .repository
                 .delete_item(&item.table_id, &item.id)
                 .await
                 .expect("Failed to delete item");
 
+            assert!(deleted);
+
             let query_result = context
                 .repository
                 .find_item(&item.table_id, &item.id)
@@ -111,12 +149,12 @@ mod repository_tests {
 
             let item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
 
             context
                 .repository
-                .save_items(&vec![item.clone()])
+                .save_items(std::slice::from_ref(&item))
                 .await
                 .expect("Failed to save item");
 
@@ -135,12 +173,12 @@ mod repository_tests {
             let context = RepositoryTestContext::create_test_context().await;
             let item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
 
             let save_result = context
                 .repository
-                .save_items(&vec![item.clone(), item.clone()])
+                .save_items(&[item.clone(), item.clone()])
                 .await;
 
             assert!(save_result.is_err());
@@ -154,29 +192,91 @@ mod repository_tests {
             assert!(query_result.is_none());
         }
 
+        #[tokio::test]
+        async fn should_create_large_batch_with_special_characters() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let items = (0..1000)
+                .map(|index| {
+                    context
+                        .factory
+                        .try_create(
+                            index % 10,
+                            format!("Pierogi \"{}\", \\ z\tserem", index),
+                            None,
+                        )
+                        .and_then(|item| item.with_notes(Some("Bez cebuli,\nproszę".to_string())))
+                        .expect("Failed to create item")
+                })
+                .collect::<Vec<_>>();
+
+            context
+                .repository
+                .save_items(&items)
+                .await
+                .expect("Failed to save items");
+
+            for item in [&items[0], &items[499], &items[999]] {
+                let saved = context
+                    .repository
+                    .find_item(&item.table_id, &item.id)
+                    .await
+                    .expect("Failed to find item")
+                    .expect("Failed to find saved item");
+
+                assert_eq!(item, &saved);
+            }
+        }
+
+        #[tokio::test]
+        async fn should_not_create_large_batch_within_transaction() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let mut items = (0..1000)
+                .map(|_| {
+                    context
+                        .factory
+                        .try_create(1, "Pierogi".to_string(), None)
+                        .expect("Failed to create item")
+                })
+                .collect::<Vec<_>>();
+            items.push(items[0].clone());
+
+            let save_result = context.repository.save_items(&items).await;
+
+            assert!(save_result.is_err());
+
+            let query_result = context
+                .repository
+                .find_item(&items[1].table_id, &items[1].id)
+                .await
+                .expect("Failed to find item");
+
+            assert!(query_result.is_none());
+        }
+
         #[tokio::test]
         async fn should_create_items_for_same_table() {
             let context = RepositoryTestContext::create_test_context().await;
             let first_item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
             let second_item = context
                 .factory
-                .try_create(1, "Schabowy".to_string())
+                .try_create(1, "Schabowy".to_string(), None)
                 .expect("Failed to create item");
 
             context
                 .repository
-                .save_items(&vec![first_item.clone(), second_item.clone()])
+                .save_items(&[first_item.clone(), second_item.clone()])
                 .await
                 .expect("Failed to save items");
 
             let query_result = context
                 .repository
-                .find_items_by_table(&first_item.table_id)
+                .find_items_by_table(&first_item.table_id, &ItemQuery::default())
                 .await
-                .expect("Failed to find item");
+                .expect("Failed to find item")
+                .items;
 
             assert_eq!(2, query_result.len());
             assert_eq!(first_item, query_result[0]);
@@ -188,45 +288,591 @@ mod repository_tests {
             let context = RepositoryTestContext::create_test_context().await;
             let first_item = context
                 .factory
-                .try_create(1, "Pierogi".to_string())
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
             let second_item = context
                 .factory
-                .try_create(2, "Schabowy".to_string())
+                .try_create(2, "Schabowy".to_string(), None)
                 .expect("Failed to create item");
 
             context
                 .repository
-                .save_items(&vec![first_item.clone()])
+                .save_items(std::slice::from_ref(&first_item))
                 .await
                 .expect("Failed to save items");
 
             context
                 .repository
-                .save_items(&vec![second_item.clone()])
+                .save_items(std::slice::from_ref(&second_item))
                 .await
                 .expect("Failed to save items");
 
             let first_table_query = context
                 .repository
-                .find_items_by_table(&first_item.table_id)
+                .find_items_by_table(&first_item.table_id, &ItemQuery::default())
                 .await
-                .expect("Failed to find item");
+                .expect("Failed to find item")
+                .items;
 
             assert_eq!(1, first_table_query.len());
             assert_eq!(first_item, first_table_query[0]);
 
             let second_table_query = context
                 .repository
-                .find_items_by_table(&second_item.table_id)
+                .find_items_by_table(&second_item.table_id, &ItemQuery::default())
                 .await
-                .expect("Failed to find item");
+                .expect("Failed to find item")
+                .items;
 
             assert_eq!(1, second_table_query.len());
             assert_eq!(second_item, second_table_query[0]);
         }
     }
 
+    mod find_items_by_table_tests {
+        use super::*;
+        use chrono::Duration;
+
+        async fn save_menu(context: &RepositoryTestContext) -> Vec<Item> {
+            let now = Utc::now().round_subsecs(6);
+            let items = ["Pierogi", "Schabowy", "Pierogi ruskie", "Bigos"]
+                .into_iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    Item::try_new(
+                        Uuid::now_v7(),
+                        1,
+                        name.to_string(),
+                        now + Duration::minutes(index as i64),
+                        Station::Grill,
+                    )
+                    .expect("Failed to create item")
+                })
+                .collect::<Vec<_>>();
+
+            context
+                .repository
+                .save_items(&items)
+                .await
+                .expect("Failed to save items");
+
+            items
+        }
+
+        #[tokio::test]
+        async fn should_paginate_with_cursor() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let items = save_menu(&context).await;
+
+            let query = ItemQuery {
+                limit: 3,
+                ..ItemQuery::default()
+            };
+            let first_page = context
+                .repository
+                .find_items_by_table(&1, &query)
+                .await
+                .expect("Failed to find items");
+
+            let query = ItemQuery {
+                after: first_page.next.clone(),
+                ..query
+            };
+            let second_page = context
+                .repository
+                .find_items_by_table(&1, &query)
+                .await
+                .expect("Failed to find items");
+
+            assert_eq!(items[..3], first_page.items);
+            assert!(first_page.next.is_some());
+            assert_eq!(items[3..], second_page.items);
+            assert!(second_page.next.is_none());
+        }
+
+        #[tokio::test]
+        async fn should_sort_descending() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let mut items = save_menu(&context).await;
+            items.reverse();
+
+            let query = ItemQuery {
+                order: SortOrder::Desc,
+                ..ItemQuery::default()
+            };
+            let page = context
+                .repository
+                .find_items_by_table(&1, &query)
+                .await
+                .expect("Failed to find items");
+
+            assert_eq!(items, page.items);
+        }
+
+        #[tokio::test]
+        async fn should_filter_by_name_and_time_window() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let items = save_menu(&context).await;
+
+            let query = ItemQuery {
+                name: Some("pierogi".to_string()),
+                prepared_after: Some(items[1].preparation_time),
+                ..ItemQuery::default()
+            };
+            let page = context
+                .repository
+                .find_items_by_table(&1, &query)
+                .await
+                .expect("Failed to find items");
+
+            assert_eq!(vec![items[2].clone()], page.items);
+        }
+
+        #[tokio::test]
+        async fn should_treat_wildcards_literally() {
+            let context = RepositoryTestContext::create_test_context().await;
+            save_menu(&context).await;
+
+            let query = ItemQuery {
+                name: Some("%".to_string()),
+                ..ItemQuery::default()
+            };
+            let page = context
+                .repository
+                .find_items_by_table(&1, &query)
+                .await
+                .expect("Failed to find items");
+
+            assert!(page.items.is_empty());
+        }
+    }
+
+    mod find_items_by_station_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn should_find_only_station_items() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let grill_item = context
+                .factory
+                .try_create(1, "Pierogi".to_string(), Some(Station::Grill))
+                .expect("Failed to create item");
+            let bar_item = context
+                .factory
+                .try_create(1, "Piwo".to_string(), Some(Station::Bar))
+                .expect("Failed to create item");
+            let ready_item = Item {
+                status: ItemStatus::Ready,
+                ..context
+                    .factory
+                    .try_create(2, "Piwo".to_string(), Some(Station::Bar))
+                    .expect("Failed to create item")
+            };
+
+            context
+                .repository
+                .save_items(&[grill_item.clone(), bar_item.clone(), ready_item.clone()])
+                .await
+                .expect("Failed to save items");
+
+            let query_result = context
+                .repository
+                .find_items_by_station(&Station::Bar)
+                .await
+                .expect("Failed to find station items");
+
+            assert_eq!(1, query_result.len());
+            assert_eq!(bar_item, query_result[0]);
+        }
+    }
+
+    mod find_pending_items_tests {
+        use super::*;
+        use chrono::Duration;
+
+        #[tokio::test]
+        async fn should_find_only_pending_items() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let pending_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                now + Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+            let completed_item = Item::try_new(
+                Uuid::now_v7(),
+                2,
+                "Schabowy".to_string(),
+                now - Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let ready_item = Item {
+                status: ItemStatus::Ready,
+                ..Item::try_new(
+                    Uuid::now_v7(),
+                    3,
+                    "Piwo".to_string(),
+                    now + Duration::minutes(5),
+                    Station::Bar,
+                )
+                .expect("Failed to create item")
+            };
+
+            context
+                .repository
+                .save_items(&[pending_item.clone(), completed_item.clone(), ready_item])
+                .await
+                .expect("Failed to save items");
+
+            let query_result = context
+                .repository
+                .find_pending_items(&now)
+                .await
+                .expect("Failed to find pending items");
+
+            assert_eq!(1, query_result.len());
+            assert_eq!(pending_item.id, query_result[0].id);
+        }
+    }
+
+    mod overdue_items_tests {
+        use super::*;
+        use chrono::Duration;
+
+        #[tokio::test]
+        async fn should_find_overdue_items_until_marked() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let overdue_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                now - Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+            let pending_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Schabowy".to_string(),
+                now + Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            context
+                .repository
+                .save_items(&[overdue_item.clone(), pending_item.clone()])
+                .await
+                .expect("Failed to save items");
+
+            let before_marking = context
+                .repository
+                .find_unnotified_overdue_items(&now)
+                .await
+                .expect("Failed to find unnotified overdue items");
+
+            context
+                .repository
+                .mark_overdue_notified(&[overdue_item.id])
+                .await
+                .expect("Failed to mark overdue items");
+
+            let after_marking = context
+                .repository
+                .find_unnotified_overdue_items(&now)
+                .await
+                .expect("Failed to find unnotified overdue items");
+
+            assert_eq!(vec![overdue_item.clone()], before_marking);
+            assert!(after_marking.is_empty());
+
+            let overdue = context
+                .repository
+                .find_overdue_items(&now)
+                .await
+                .expect("Failed to find overdue items");
+
+            assert_eq!(vec![overdue_item], overdue);
+        }
+
+        #[tokio::test]
+        async fn should_not_report_ready_items_as_overdue() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let ready_item = Item {
+                status: ItemStatus::Ready,
+                ..Item::try_new(
+                    Uuid::now_v7(),
+                    1,
+                    "Pierogi".to_string(),
+                    now - Duration::minutes(5),
+                    Station::Grill,
+                )
+                .expect("Failed to create item")
+            };
+
+            context
+                .repository
+                .save_items(&[ready_item])
+                .await
+                .expect("Failed to save items");
+
+            let unnotified = context
+                .repository
+                .find_unnotified_overdue_items(&now)
+                .await
+                .expect("Failed to find unnotified overdue items");
+            let overdue = context
+                .repository
+                .find_overdue_items(&now)
+                .await
+                .expect("Failed to find overdue items");
+
+            assert!(unnotified.is_empty());
+            assert!(overdue.is_empty());
+        }
+    }
+
+    mod advance_ready_items_tests {
+        use super::*;
+        use chrono::Duration;
+
+        #[tokio::test]
+        async fn should_advance_ready_items_with_audit() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let ready_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                now - Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+            let pending_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Schabowy".to_string(),
+                now + Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            context
+                .repository
+                .save_items(&[ready_item.clone(), pending_item.clone()])
+                .await
+                .expect("Failed to save items");
+
+            let advanced = context
+                .repository
+                .advance_ready_items(&now, SYSTEM_ACTOR)
+                .await
+                .expect("Failed to advance items");
+
+            let advanced_again = context
+                .repository
+                .advance_ready_items(&now, SYSTEM_ACTOR)
+                .await
+                .expect("Failed to advance items");
+
+            assert_eq!(1, advanced.len());
+            assert_eq!(ready_item.id, advanced[0].id);
+            assert_eq!(ItemStatus::Ready, advanced[0].status);
+            assert!(advanced_again.is_empty());
+
+            let pending = context
+                .repository
+                .find_item(&pending_item.table_id, &pending_item.id)
+                .await
+                .expect("Failed to find item")
+                .expect("Failed to find pending item");
+
+            assert_eq!(ItemStatus::Pending, pending.status);
+
+            let audit = context
+                .repository
+                .find_audit_entries(&ready_item.id)
+                .await
+                .expect("Failed to find audit entries");
+
+            assert_eq!(1, audit.len());
+            assert_eq!(ItemStatus::Pending, audit[0].from_status);
+            assert_eq!(ItemStatus::Ready, audit[0].to_status);
+            assert_eq!(SYSTEM_ACTOR, audit[0].actor);
+        }
+    }
+
+    mod update_item_tests {
+        use super::*;
+        use chrono::Duration;
+
+        #[tokio::test]
+        async fn should_update_item_before_preparation() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                now + Duration::minutes(30),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            context
+                .repository
+                .save_items(std::slice::from_ref(&item))
+                .await
+                .expect("Failed to save item");
+
+            let changed = Item {
+                name: "Pierogi ruskie".to_string(),
+                notes: Some("No onions".to_string()),
+                ..item.clone()
+            };
+
+            let updated = context
+                .repository
+                .update_item(&changed, &now)
+                .await
+                .expect("Failed to update item");
+
+            assert_eq!(Some(changed.clone()), updated);
+
+            let found = context
+                .repository
+                .find_item(&item.table_id, &item.id)
+                .await
+                .expect("Failed to find item");
+
+            assert_eq!(Some(changed), found);
+        }
+
+        #[tokio::test]
+        async fn should_not_update_item_in_preparation() {
+            let context = RepositoryTestContext::create_test_context().await;
+            let now = Utc::now().round_subsecs(6);
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                now + Duration::minutes(5),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            context
+                .repository
+                .save_items(std::slice::from_ref(&item))
+                .await
+                .expect("Failed to save item");
+
+            let changed = Item {
+                name: "Pierogi ruskie".to_string(),
+                ..item.clone()
+            };
+
+            let updated = context
+                .repository
+                .update_item(&changed, &(now + Duration::minutes(10)))
+                .await
+                .expect("Failed to update item");
+
+            assert_eq!(None, updated);
+
+            let found = context
+                .repository
+                .find_item(&item.table_id, &item.id)
+                .await
+                .expect("Failed to find item");
+
+            assert_eq!(Some(item), found);
+        }
+    }
+
+    mod save_items_benchmark {
+        use super::*;
+        use std::time::{Duration, Instant};
+
+        const INSERT_ITEM: &str = "INSERT INTO items (id, table_id, name, preparation_time, station, status, notes) VALUES ($1, $2, $3, $4, $5, $6, $7)";
+
+        // The previous implementation, one round trip per item inside a transaction.
+        async fn save_items_row_by_row(context: &RepositoryTestContext, items: &[Item]) {
+            let mut transaction = context
+                .pool
+                .begin()
+                .await
+                .expect("Failed to begin transaction");
+
+            for item in items {
+                sqlx::query(INSERT_ITEM)
+                    .bind(item.id)
+                    .bind(item.table_id)
+                    .bind(&item.name)
+                    .bind(item.preparation_time)
+                    .bind(item.station.as_str())
+                    .bind(item.status.as_str())
+                    .bind(&item.notes)
+                    .execute(&mut *transaction)
+                    .await
+                    .expect("Failed to insert item");
+            }
+
+            transaction
+                .commit()
+                .await
+                .expect("Failed to commit transaction");
+        }
+
+        fn create_items(context: &RepositoryTestContext, count: usize) -> Vec<Item> {
+            (0..count)
+                .map(|index| {
+                    context
+                        .factory
+                        .try_create(index as i64 % 50, "Pierogi".to_string(), None)
+                        .expect("Failed to create item")
+                })
+                .collect()
+        }
+
+        #[tokio::test]
+        #[ignore = "benchmark, run with --ignored --nocapture"]
+        async fn benchmark_save_items() {
+            let context = RepositoryTestContext::create_test_context().await;
+
+            for size in [10, 100, 1_000, 10_000] {
+                let items = create_items(&context, size);
+                let start = Instant::now();
+                save_items_row_by_row(&context, &items).await;
+                let row_by_row = start.elapsed();
+
+                let items = create_items(&context, size);
+                let start = Instant::now();
+                context
+                    .repository
+                    .save_items(&items)
+                    .await
+                    .expect("Failed to save items");
+                let bulk = start.elapsed();
+
+                println!(
+                    "{:>6} items: row by row {:>10.2?}, bulk {:>10.2?}, speedup {:.1}x",
+                    size,
+                    row_by_row,
+                    bulk,
+                    row_by_row.as_secs_f64() / bulk.max(Duration::from_micros(1)).as_secs_f64()
+                );
+            }
+        }
+    }
+
     #[tokio::test]
     async fn should_not_get_item_for_bad_mapping() {
         let context = RepositoryTestContext::create_test_context().await;
@@ -235,11 +881,14 @@ mod repository_tests {
             table_id: -1,
             name: "".to_string(),
             preparation_time: Utc::now(),
+            station: Station::Grill,
+            status: ItemStatus::Pending,
+            notes: None,
         };
 
         context
             .repository
-            .save_items(&vec![invalid_item.clone()])
+            .save_items(std::slice::from_ref(&invalid_item))
             .await
             .expect("Failed to save item");
 
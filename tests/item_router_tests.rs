@@ -1,5 +1,7 @@
 mod item_router_tests {
     use paidy_submission::application::item_service::MockItemService;
+    use paidy_submission::domain::item::{ItemStatus, OverduePolicy};
+    use paidy_submission::domain::station::Station;
     use paidy_submission::web::item_endpoint::ItemRouter;
     use reqwest::Client;
     use std::net::SocketAddr;
@@ -19,7 +21,10 @@ mod item_router_tests {
 
             let address = listener.local_addr().expect("Failed to get local address");
 
-            let router = ItemRouter::create(Arc::new(service));
+            let router = ItemRouter::with_custom_methods(ItemRouter::create(
+                Arc::new(service),
+                OverduePolicy::default(),
+            ));
 
             tokio::spawn(async move {
                 axum::serve(listener, router).await.expect("Error");
@@ -31,25 +36,41 @@ mod item_router_tests {
         }
     }
 
+    #[allow(clippy::module_inception)]
     mod item_router_tests {
-        const RAW_NOT_FOUND: &str = r#"{"message":"Resource not found"}"#;
-        const RAW_PATH_PARSE_ERROR: &str = r#"{"message":"Failed to extract the path parameter."}"#;
-        const RAW_JSON_PARSE_ERROR: &str = r#"{"message":"Failed to deserialize the JSON body."}"#;
-        const RAW_ITEM: &str = r#"{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z"}"#;
-        const RAW_ITEMS: &str = r#"{"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z"},{"id":"16a1eab3-2028-470f-8c2c-3d50a1997939","table_id":1,"name":"Schabowy","preparation_time":"2024-11-25T00:00:00Z"}]}"#;
+        const RAW_NOT_FOUND: &str = r#"{"code":"not_found","message":"Resource not found"}"#;
+        const RAW_PATH_PARSE_ERROR: &str =
+            r#"{"code":"invalid_path","message":"Failed to extract the path parameter."}"#;
+        const RAW_JSON_PARSE_ERROR: &str =
+            r#"{"code":"invalid_body","message":"Failed to deserialize the JSON body."}"#;
+        const RAW_CONTENT_TYPE_ERROR: &str =
+            r#"{"code":"missing_content_type","message":"Failed to deserialize the JSON body."}"#;
+        const RAW_ITEM: &str = r#"{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z","station":"grill","status":"pending","remaining_seconds":0,"is_overdue":true}"#;
+        const RAW_ITEMS: &str = r#"{"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z","station":"grill","status":"pending","remaining_seconds":0,"is_overdue":true},{"id":"16a1eab3-2028-470f-8c2c-3d50a1997939","table_id":1,"name":"Schabowy","preparation_time":"2024-11-25T00:00:00Z","station":"grill","status":"pending","remaining_seconds":0,"is_overdue":true}]}"#;
         const RAW_EMPTY: &str = r#"{"items":[]}"#;
+        const RAW_CURSOR: &str =
+            "MTczMjQwNjQwMDAwMDAwMHwwMTkzNWRmZS05N2NmLTczYjItYmU0Yy0xNWIzYWNmYzYwN2U";
+        const RAW_QUERY_PARSE_ERROR: &str =
+            r#"{"code":"invalid_query","message":"Failed to deserialize the query parameters."}"#;
 
         mod get_item_endpoint {
             use super::*;
-            use crate::item_router_tests::ItemRouterTestContext;
-            use chrono::DateTime;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
+            use chrono::{DateTime, TimeDelta, Utc};
             use mockall::predicate::eq;
             use paidy_submission::application::item_service::{
                 ApplicationError, ItemModel, MockItemService,
             };
+            use serde::Deserialize;
             use std::str::FromStr;
             use uuid::Uuid;
 
+            #[derive(Deserialize)]
+            struct TimingBody {
+                remaining_seconds: i64,
+                is_overdue: bool,
+            }
+
             #[tokio::test]
             async fn should_get_item() {
                 let model = ItemModel {
@@ -59,6 +80,9 @@ mod item_router_tests {
                     name: "Pierogi".to_string(),
                     preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
                         .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
                 };
 
                 let mut service = MockItemService::new();
@@ -89,6 +113,121 @@ mod item_router_tests {
                 assert_eq!(body, RAW_ITEM);
             }
 
+            #[tokio::test]
+            async fn should_get_item_without_timing() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_get_item()
+                    .return_const(Ok(model.clone()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}?timing=false",
+                    context.address, model.table_id, model.id
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 200);
+                assert_eq!(
+                    body,
+                    r#"{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z","station":"grill","status":"pending"}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_get_remaining_time_of_pending_item() {
+                let model = ItemModel {
+                    id: Uuid::now_v7(),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: Utc::now() + TimeDelta::minutes(10),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_get_item()
+                    .return_const(Ok(model.clone()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}",
+                    context.address, model.table_id, model.id
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response
+                    .json::<TimingBody>()
+                    .await
+                    .expect("Failed to get body");
+
+                assert_eq!(status, 200);
+                assert!(!body.is_overdue);
+                assert!(body.remaining_seconds > 590 && body.remaining_seconds <= 600);
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_bad_timing() {
+                let item_id = Uuid::now_v7();
+
+                let mut service = MockItemService::new();
+                service.expect_get_item().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}?timing=maybe",
+                    context.address, 1, item_id
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_QUERY_PARSE_ERROR);
+            }
+
             #[tokio::test]
             async fn should_return_resource_not_found() {
                 let item_id = Uuid::now_v7();
@@ -121,6 +260,42 @@ mod item_router_tests {
                 assert_eq!(body, RAW_NOT_FOUND);
             }
 
+            #[tokio::test]
+            async fn should_return_service_unavailable() {
+                let mut service = MockItemService::new();
+                service
+                    .expect_get_item()
+                    .return_const(Err(ApplicationError::Unavailable))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}",
+                    context.address,
+                    1,
+                    Uuid::now_v7()
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+                let retry_after = response.headers().get("Retry-After").cloned();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 503);
+                assert_eq!(retry_after.as_ref().map(|v| v.as_bytes()), Some(&b"5"[..]));
+                assert_eq!(
+                    body,
+                    r#"{"code":"service_unavailable","message":"Service is temporarily unavailable."}"#
+                );
+            }
+
             #[tokio::test]
             async fn should_reject_request_bad_table_id() {
                 let item_id = Uuid::now_v7();
@@ -182,10 +357,13 @@ mod item_router_tests {
 
         mod get_items_endpoint {
             use super::*;
-            use crate::item_router_tests::ItemRouterTestContext;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
             use chrono::DateTime;
             use mockall::predicate::eq;
-            use paidy_submission::application::item_service::{ItemModel, MockItemService};
+            use paidy_submission::application::item_service::{
+                ItemModel, ItemModelPage, MockItemService,
+            };
+            use paidy_submission::domain::item_query::{ItemCursor, ItemQuery, SortOrder};
             use std::str::FromStr;
             use uuid::Uuid;
 
@@ -198,6 +376,9 @@ mod item_router_tests {
                     name: "Pierogi".to_string(),
                     preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
                         .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
                 };
 
                 let second_model = ItemModel {
@@ -207,13 +388,19 @@ mod item_router_tests {
                     name: "Schabowy".to_string(),
                     preparation_time: DateTime::from_str("2024-11-25T00:00:00Z")
                         .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
                 };
 
                 let mut service = MockItemService::new();
                 service
                     .expect_get_items()
-                    .with(eq(1))
-                    .return_const(Ok(vec![first_model.clone(), second_model.clone()]))
+                    .with(eq(1), eq(ItemQuery::default()))
+                    .return_const(Ok(ItemModelPage {
+                        items: vec![first_model.clone(), second_model.clone()],
+                        next: None,
+                    }))
                     .once();
 
                 let context = ItemRouterTestContext::new(service).await;
@@ -239,8 +426,8 @@ mod item_router_tests {
                 let mut service = MockItemService::new();
                 service
                     .expect_get_items()
-                    .with(eq(1))
-                    .return_const(Ok(vec![]))
+                    .with(eq(1), eq(ItemQuery::default()))
+                    .return_const(Ok(ItemModelPage::default()))
                     .once();
 
                 let context = ItemRouterTestContext::new(service).await;
@@ -283,68 +470,47 @@ mod item_router_tests {
                 assert_eq!(status, 422);
                 assert_eq!(body, RAW_PATH_PARSE_ERROR);
             }
-        }
-
-        mod create_items_endpoint {
-            use super::*;
-            use crate::item_router_tests::ItemRouterTestContext;
-            use chrono::DateTime;
-            use mockall::predicate::eq;
-            use paidy_submission::application::item_service::{
-                CreateItemModel, CreateItemsCommand, ItemModel, MockItemService,
-            };
-            use std::str::FromStr;
-            use uuid::Uuid;
-
-            const RAW_COMMAND: &str = r#"{"items":[{"name":"Pierogi"},{"name":"Schabowy"}]}"#;
-            const RAW_BAD_COMMAND: &str = r#"{"ite":[{"name":"Pierogi"},{"name":"Schabowy"}]}"#;
 
             #[tokio::test]
-            async fn should_create_items() {
-                let command = CreateItemsCommand {
-                    items: vec![
-                        CreateItemModel {
-                            name: "Pierogi".to_string(),
-                        },
-                        CreateItemModel {
-                            name: "Schabowy".to_string(),
-                        },
-                    ],
-                };
-
-                let first_model = ItemModel {
-                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
-                        .expect("Failed to parse UUID"),
-                    table_id: 1,
-                    name: "Pierogi".to_string(),
+            async fn should_pass_filters_and_return_next_cursor() {
+                let cursor = ItemCursor {
                     preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
                         .expect("Failed to parse date"),
-                };
-
-                let second_model = ItemModel {
-                    id: Uuid::parse_str("16a1eab3-2028-470f-8c2c-3d50a1997939")
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
                         .expect("Failed to parse UUID"),
-                    table_id: 1,
-                    name: "Schabowy".to_string(),
-                    preparation_time: DateTime::from_str("2024-11-25T00:00:00Z")
-                        .expect("Failed to parse date"),
+                };
+                let query = ItemQuery {
+                    name: Some("pier".to_string()),
+                    prepared_after: Some(
+                        DateTime::from_str("2024-11-23T00:00:00Z").expect("Failed to parse date"),
+                    ),
+                    prepared_before: Some(
+                        DateTime::from_str("2024-11-25T00:00:00Z").expect("Failed to parse date"),
+                    ),
+                    order: SortOrder::Desc,
+                    after: Some(cursor.clone()),
+                    limit: 1,
                 };
 
                 let mut service = MockItemService::new();
                 service
-                    .expect_create_items()
-                    .with(eq(1), eq(command))
-                    .return_const(Ok(vec![first_model.clone(), second_model.clone()]))
+                    .expect_get_items()
+                    .with(eq(1), eq(query))
+                    .return_const(Ok(ItemModelPage {
+                        items: vec![],
+                        next: Some(cursor),
+                    }))
                     .once();
 
                 let context = ItemRouterTestContext::new(service).await;
 
-                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let url = format!(
+                    "http://{}/tables/{}/items?name=pier&prepared_after=2024-11-23T00:00:00Z&prepared_before=2024-11-25T00:00:00Z&order=desc&limit=1&cursor={}",
+                    context.address, 1, RAW_CURSOR
+                );
                 let response = context
                     .client
-                    .post(url)
-                    .header("Content-Type", "application/json")
-                    .body(RAW_COMMAND)
+                    .get(url)
                     .send()
                     .await
                     .expect("Failed to get response");
@@ -353,23 +519,27 @@ mod item_router_tests {
 
                 let body = response.text().await.expect("Failed to get body");
 
-                assert_eq!(status, 201);
-                assert_eq!(body, RAW_ITEMS);
+                assert_eq!(status, 200);
+                assert_eq!(
+                    body,
+                    format!(r#"{{"items":[],"next_cursor":"{}"}}"#, RAW_CURSOR)
+                );
             }
 
             #[tokio::test]
-            async fn should_reject_request_bad_table_id() {
+            async fn should_reject_request_bad_cursor() {
                 let mut service = MockItemService::new();
-                service.expect_create_items().never();
+                service.expect_get_items().never();
 
                 let context = ItemRouterTestContext::new(service).await;
 
-                let url = format!("http://{}/tables/{}/items", context.address, "bad_table");
+                let url = format!(
+                    "http://{}/tables/{}/items?cursor={}",
+                    context.address, 1, "bad_cursor"
+                );
                 let response = context
                     .client
-                    .post(url)
-                    .header("Content-Type", "application/json")
-                    .body(RAW_COMMAND)
+                    .get(url)
                     .send()
                     .await
                     .expect("Failed to get response");
@@ -378,21 +548,24 @@ mod item_router_tests {
 
                 let body = response.text().await.expect("Failed to get body");
 
-                assert_eq!(status, 422);
-                assert_eq!(body, RAW_PATH_PARSE_ERROR);
+                assert_eq!(status, 400);
+                assert_eq!(
+                    body,
+                    r#"{"code":"invalid_cursor","message":"Invalid cursor."}"#
+                );
             }
 
             #[tokio::test]
-            async fn should_reject_request_no_body() {
+            async fn should_reject_request_bad_limit() {
                 let mut service = MockItemService::new();
-                service.expect_create_items().never();
+                service.expect_get_items().never();
 
                 let context = ItemRouterTestContext::new(service).await;
 
-                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let url = format!("http://{}/tables/{}/items?limit=-1", context.address, 1);
                 let response = context
                     .client
-                    .post(url)
+                    .get(url)
                     .send()
                     .await
                     .expect("Failed to get response");
@@ -402,22 +575,39 @@ mod item_router_tests {
                 let body = response.text().await.expect("Failed to get body");
 
                 assert_eq!(status, 422);
-                assert_eq!(body, RAW_JSON_PARSE_ERROR);
+                assert_eq!(body, RAW_QUERY_PARSE_ERROR);
             }
 
             #[tokio::test]
-            async fn should_reject_request_bad_body() {
+            async fn should_get_items_summary() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
                 let mut service = MockItemService::new();
-                service.expect_create_items().never();
+                service
+                    .expect_get_items()
+                    .with(eq(1), eq(ItemQuery::default()))
+                    .return_const(Ok(ItemModelPage {
+                        items: vec![model],
+                        next: None,
+                    }))
+                    .once();
 
                 let context = ItemRouterTestContext::new(service).await;
 
-                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let url = format!("http://{}/tables/{}/items?view=summary", context.address, 1);
                 let response = context
                     .client
-                    .post(url)
-                    .header("Content-Type", "application/json")
-                    .body(RAW_BAD_COMMAND)
+                    .get(url)
                     .send()
                     .await
                     .expect("Failed to get response");
@@ -426,31 +616,766 @@ mod item_router_tests {
 
                 let body = response.text().await.expect("Failed to get body");
 
-                assert_eq!(status, 422);
-                assert_eq!(body, RAW_JSON_PARSE_ERROR);
+                assert_eq!(status, 200);
+                assert_eq!(
+                    body,
+                    r#"{"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","name":"Pierogi"}]}"#
+                );
             }
-        }
-
-        mod delete_item_endpoint {
-            use super::*;
-            use crate::item_router_tests::ItemRouterTestContext;
-            use mockall::predicate::eq;
-            use paidy_submission::application::item_service::MockItemService;
-            use uuid::Uuid;
 
             #[tokio::test]
-            async fn should_delete_item() {
-                let item_id = Uuid::now_v7();
+            async fn should_get_selected_fields() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
                 let mut service = MockItemService::new();
                 service
-                    .expect_delete_item()
-                    .with(eq(1), eq(item_id))
-                    .return_const(Ok(()))
+                    .expect_get_items()
+                    .with(eq(1), eq(ItemQuery::default()))
+                    .return_const(Ok(ItemModelPage {
+                        items: vec![model],
+                        next: None,
+                    }))
                     .once();
 
                 let context = ItemRouterTestContext::new(service).await;
 
-                let url = format!("http://{}/tables/{}/items/{}", context.address, 1, item_id);
+                let url = format!(
+                    "http://{}/tables/{}/items?fields=name,status,is_overdue",
+                    context.address, 1
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 200);
+                assert_eq!(
+                    body,
+                    r#"{"items":[{"is_overdue":true,"name":"Pierogi","status":"pending"}]}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_unknown_field() {
+                let mut service = MockItemService::new();
+                service.expect_get_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items?fields=name,price",
+                    context.address, 1
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 400);
+                assert_eq!(
+                    body,
+                    r#"{"code":"unknown_field","message":"Unknown field: price. Known fields are: id, table_id, name, preparation_time, station, status, notes, remaining_seconds, is_overdue."}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_unknown_parameter() {
+                let mut service = MockItemService::new();
+                service.expect_get_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items?sort_by=name&timing=false",
+                    context.address, 1
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 400);
+                assert_eq!(
+                    body,
+                    r#"{"code":"unknown_parameter","message":"Unknown query parameter: sort_by. Known parameters are: timing, cursor, limit, name, prepared_after, prepared_before, order, view, fields."}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_view_with_fields() {
+                let mut service = MockItemService::new();
+                service.expect_get_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items?view=summary&fields=name",
+                    context.address, 1
+                );
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                assert_eq!(status, 400);
+            }
+        }
+
+        mod get_station_items_endpoint {
+            use super::*;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
+            use chrono::DateTime;
+            use mockall::predicate::eq;
+            use paidy_submission::application::item_service::{ItemModel, MockItemService};
+            use std::str::FromStr;
+            use uuid::Uuid;
+
+            const RAW_STATION_ITEMS: &str = r#"{"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Piwo","preparation_time":"2024-11-24T00:00:00Z","station":"bar","status":"pending","remaining_seconds":0,"is_overdue":true}]}"#;
+
+            #[tokio::test]
+            async fn should_get_station_items() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Piwo".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Bar,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_get_station_items()
+                    .with(eq(Station::Bar))
+                    .return_const(Ok(vec![model.clone()]))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/stations/{}/items", context.address, "bar");
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 200);
+                assert_eq!(body, RAW_STATION_ITEMS);
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_unknown_station() {
+                let mut service = MockItemService::new();
+                service.expect_get_station_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/stations/{}/items", context.address, "oven");
+                let response = context
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_PATH_PARSE_ERROR);
+            }
+        }
+
+        mod create_items_endpoint {
+            use super::*;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
+            use chrono::DateTime;
+            use mockall::predicate::eq;
+            use paidy_submission::application::item_service::{
+                CreateItemModel, CreateItemsCommand, ItemModel, MockItemService,
+            };
+            use std::str::FromStr;
+            use uuid::Uuid;
+
+            const RAW_COMMAND: &str = r#"{"items":[{"name":"Pierogi"},{"name":"Schabowy"}]}"#;
+            const RAW_BAD_COMMAND: &str = r#"{"ite":[{"name":"Pierogi"},{"name":"Schabowy"}]}"#;
+
+            #[tokio::test]
+            async fn should_create_items() {
+                let command = CreateItemsCommand {
+                    items: vec![
+                        CreateItemModel {
+                            name: "Pierogi".to_string(),
+                            station: None,
+                        },
+                        CreateItemModel {
+                            name: "Schabowy".to_string(),
+                            station: None,
+                        },
+                    ],
+                };
+
+                let first_model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let second_model = ItemModel {
+                    id: Uuid::parse_str("16a1eab3-2028-470f-8c2c-3d50a1997939")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Schabowy".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-25T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_create_items()
+                    .with(eq(1), eq(command))
+                    .return_const(Ok(vec![first_model.clone(), second_model.clone()]))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_COMMAND)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 201);
+                assert_eq!(body, RAW_ITEMS);
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_bad_table_id() {
+                let mut service = MockItemService::new();
+                service.expect_create_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items", context.address, "bad_table");
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_COMMAND)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_PATH_PARSE_ERROR);
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_no_body() {
+                let mut service = MockItemService::new();
+                service.expect_create_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let response = context
+                    .client
+                    .post(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_CONTENT_TYPE_ERROR);
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_bad_body() {
+                let mut service = MockItemService::new();
+                service.expect_create_items().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items", context.address, 1);
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_BAD_COMMAND)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_JSON_PARSE_ERROR);
+            }
+        }
+
+        mod create_batch_endpoint {
+            use super::*;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
+            use chrono::DateTime;
+            use paidy_submission::application::error_code::ErrorCode;
+            use paidy_submission::application::item_service::{
+                BatchErrorModel, BatchModel, BatchTableModel, ItemModel, MockItemService,
+            };
+            use std::str::FromStr;
+            use uuid::Uuid;
+
+            const RAW_BATCH_COMMAND: &str = r#"{"mode":"partial","items":[{"table_id":1,"name":"Piwo"},{"table_id":2,"name":""}]}"#;
+
+            #[tokio::test]
+            async fn should_create_batch_with_errors() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Piwo".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Bar,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_create_batch()
+                    .return_const(Ok(BatchModel {
+                        tables: vec![BatchTableModel {
+                            table_id: 1,
+                            items: vec![model],
+                        }],
+                        errors: vec![BatchErrorModel {
+                            index: 1,
+                            table_id: 2,
+                            code: ErrorCode::EmptyName,
+                            field: "name",
+                            message: "Name cannot be empty.".to_string(),
+                        }],
+                    }))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/items:batch?timing=false", context.address);
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_BATCH_COMMAND)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 207);
+                assert_eq!(
+                    body,
+                    r#"{"tables":[{"table_id":1,"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Piwo","preparation_time":"2024-11-24T00:00:00Z","station":"bar","status":"pending"}]}],"errors":[{"index":1,"table_id":2,"code":"empty_name","field":"name","message":"Name cannot be empty."}]}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_create_batch() {
+                let mut service = MockItemService::new();
+                service
+                    .expect_create_batch()
+                    .return_const(Ok(BatchModel::default()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/items:batch", context.address);
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_BATCH_COMMAND)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 201);
+                assert_eq!(body, r#"{"tables":[]}"#);
+            }
+
+            #[tokio::test]
+            async fn should_not_route_unknown_action() {
+                let mut service = MockItemService::new();
+                service.expect_create_batch().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/items:foo", context.address);
+                for body in [
+                    RAW_BATCH_COMMAND,
+                    r#"{"items":[{"name":"Piwo"}]}"#,
+                    "not json",
+                ] {
+                    let response = context
+                        .client
+                        .post(&url)
+                        .header("Content-Type", "application/json")
+                        .body(body)
+                        .send()
+                        .await
+                        .expect("Failed to get response");
+
+                    assert_eq!(response.status(), 404);
+                }
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_bad_body() {
+                let mut service = MockItemService::new();
+                service.expect_create_batch().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/items:batch", context.address);
+                let response = context
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(r#"{"items":[{"name":"Piwo"}]}"#)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_JSON_PARSE_ERROR);
+            }
+        }
+
+        mod update_item_endpoint {
+            use super::*;
+            use crate::item_router_tests::{ItemRouterTestContext, ItemStatus, Station};
+            use chrono::DateTime;
+            use mockall::predicate::eq;
+            use paidy_submission::application::item_service::{
+                ApplicationError, ItemModel, MockItemService, UpdateItemCommand,
+            };
+            use std::str::FromStr;
+            use uuid::Uuid;
+
+            const RAW_UPDATE: &str = r#"{"name":"Pierogi","notes":"No onions"}"#;
+
+            #[tokio::test]
+            async fn should_update_item() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: Some("No onions".to_string()),
+                };
+                let command = UpdateItemCommand {
+                    name: Some("Pierogi".to_string()),
+                    notes: Some(Some("No onions".to_string())),
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_update_item()
+                    .with(eq(1), eq(model.id), eq(command))
+                    .return_const(Ok(model.clone()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}?timing=false",
+                    context.address, 1, model.id
+                );
+                let response = context
+                    .client
+                    .patch(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_UPDATE)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 200);
+                assert_eq!(
+                    body,
+                    r#"{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z","station":"grill","status":"pending","notes":"No onions"}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_clear_notes_with_null() {
+                let model = ItemModel {
+                    id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                        .expect("Failed to parse UUID"),
+                    table_id: 1,
+                    name: "Pierogi".to_string(),
+                    preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                        .expect("Failed to parse date"),
+                    station: Station::Grill,
+                    status: ItemStatus::Pending,
+                    notes: None,
+                };
+                let command = UpdateItemCommand {
+                    name: None,
+                    notes: Some(None),
+                };
+
+                let mut service = MockItemService::new();
+                service
+                    .expect_update_item()
+                    .with(eq(1), eq(model.id), eq(command))
+                    .return_const(Ok(model.clone()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}?timing=false",
+                    context.address, 1, model.id
+                );
+                let response = context
+                    .client
+                    .patch(url)
+                    .header("Content-Type", "application/json")
+                    .body(r#"{"notes":null}"#)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                assert_eq!(response.status(), 200);
+            }
+
+            #[tokio::test]
+            async fn should_return_conflict_for_item_in_preparation() {
+                let item_id = Uuid::now_v7();
+                let mut service = MockItemService::new();
+                service
+                    .expect_update_item()
+                    .return_const(Err(ApplicationError::Conflict(
+                        "Item preparation has already started.".to_string(),
+                    )))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items/{}", context.address, 1, item_id);
+                let response = context
+                    .client
+                    .patch(url)
+                    .header("Content-Type", "application/json")
+                    .body(RAW_UPDATE)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 409);
+                assert_eq!(
+                    body,
+                    r#"{"code":"conflict","message":"Item preparation has already started."}"#
+                );
+            }
+
+            #[tokio::test]
+            async fn should_reject_request_bad_body() {
+                let item_id = Uuid::now_v7();
+                let mut service = MockItemService::new();
+                service.expect_update_item().never();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items/{}", context.address, 1, item_id);
+                let response = context
+                    .client
+                    .patch(url)
+                    .header("Content-Type", "application/json")
+                    .body(r#"{"name":1}"#)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 422);
+                assert_eq!(body, RAW_JSON_PARSE_ERROR);
+            }
+        }
+
+        mod delete_item_endpoint {
+            use super::*;
+            use crate::item_router_tests::ItemRouterTestContext;
+            use mockall::predicate::eq;
+            use paidy_submission::application::item_service::{ApplicationError, MockItemService};
+            use uuid::Uuid;
+
+            #[tokio::test]
+            async fn should_delete_item() {
+                let item_id = Uuid::now_v7();
+                let mut service = MockItemService::new();
+                service
+                    .expect_delete_item()
+                    .with(eq(1), eq(item_id))
+                    .return_const(Ok(()))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items/{}", context.address, 1, item_id);
+                let response = context
+                    .client
+                    .delete(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 204);
+                assert!(body.is_empty());
+            }
+
+            #[tokio::test]
+            async fn should_return_resource_not_found() {
+                let item_id = Uuid::now_v7();
+                let mut service = MockItemService::new();
+                service
+                    .expect_delete_item()
+                    .with(eq(1), eq(item_id))
+                    .return_const(Err(ApplicationError::ResourceNotFound))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!("http://{}/tables/{}/items/{}", context.address, 1, item_id);
+                let response = context
+                    .client
+                    .delete(url)
+                    .send()
+                    .await
+                    .expect("Failed to get response");
+
+                let status = response.status();
+
+                let body = response.text().await.expect("Failed to get body");
+
+                assert_eq!(status, 404);
+                assert_eq!(body, RAW_NOT_FOUND);
+            }
+
+            #[tokio::test]
+            async fn should_delete_missing_item_idempotently() {
+                let item_id = Uuid::now_v7();
+                let mut service = MockItemService::new();
+                service
+                    .expect_delete_item()
+                    .with(eq(1), eq(item_id))
+                    .return_const(Err(ApplicationError::ResourceNotFound))
+                    .once();
+
+                let context = ItemRouterTestContext::new(service).await;
+
+                let url = format!(
+                    "http://{}/tables/{}/items/{}?idempotent=true",
+                    context.address, 1, item_id
+                );
                 let response = context
                     .client
                     .delete(url)
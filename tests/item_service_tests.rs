@@ -4,6 +4,7 @@ use paidy_submission::application::item_service::{
 };
 use paidy_submission::domain::item::Item;
 use paidy_submission::domain::repository::{MockItemRepository, RepositoryError};
+use paidy_submission::domain::station::Station;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -13,6 +14,7 @@ mod item_service_tests {
     mod delete_item_tests {
         use super::*;
         use paidy_submission::domain::item_factory::MockItemFactory;
+        use paidy_submission::domain::metrics::MockItemMetrics;
 
         #[tokio::test]
         async fn should_delete_item() {
@@ -22,256 +24,1065 @@ mod item_service_tests {
             repository
                 .expect_delete_item()
                 .with(eq(table_id), eq(item_id))
+                .return_const(Ok(true))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            service
+                .delete_item(table_id, item_id)
+                .await
+                .expect("Failed to delete item");
+        }
+
+        #[tokio::test]
+        async fn should_count_deleted_item() {
+            let table_id = 1;
+            let item_id = Uuid::now_v7();
+            let mut repository = MockItemRepository::new();
+            repository.expect_delete_item().return_const(Ok(true));
+            let mut metrics = MockItemMetrics::new();
+            metrics
+                .expect_item_deleted()
+                .with(eq(table_id))
+                .return_const(())
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()))
+                    .with_metrics(Arc::new(metrics));
+
+            service
+                .delete_item(table_id, item_id)
+                .await
+                .expect("Failed to delete item");
+        }
+
+        #[tokio::test]
+        async fn should_get_resource_not_found_on_missing_item() {
+            let table_id = 1;
+            let item_id = Uuid::now_v7();
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_delete_item()
+                .with(eq(table_id), eq(item_id))
+                .return_const(Ok(false))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .delete_item(table_id, item_id)
+                .await
+                .expect_err("Delete item did not fail");
+
+            assert_eq!(result, ApplicationError::ResourceNotFound);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let table_id = 1;
+            let item_id = Uuid::now_v7();
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_delete_item()
+                .with(eq(table_id), eq(item_id))
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .delete_item(table_id, item_id)
+                .await
+                .expect_err("Repository delete did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+    }
+
+    mod get_item_tests {
+        use super::*;
+        use chrono::Utc;
+        use paidy_submission::domain::item::Item;
+        use paidy_submission::domain::item_factory::MockItemFactory;
+
+        #[tokio::test]
+        async fn should_get_item() {
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_item()
+                .return_const(Ok(Some(item.clone())))
+                .with(eq(item.table_id), eq(item.id))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_item(item.table_id, item.id)
+                .await
+                .expect("Failed to get item");
+
+            assert_eq!(result.id, item.id);
+            assert_eq!(result.table_id, item.table_id);
+            assert_eq!(result.name, item.name);
+            assert_eq!(result.preparation_time, item.preparation_time);
+        }
+
+        #[tokio::test]
+        async fn should_get_resource_not_found_on_missing_item() {
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_item()
+                .return_const(Ok(None))
+                .with(eq(item.table_id), eq(item.id))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_item(item.table_id, item.id)
+                .await
+                .expect_err("Get item did not fail");
+
+            assert_eq!(result, ApplicationError::ResourceNotFound);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_item()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .with(eq(item.table_id), eq(item.id))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_item(item.table_id, item.id)
+                .await
+                .expect_err("Get item did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+
+        #[tokio::test]
+        async fn should_handle_unavailable_repository() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_item()
+                .return_const(Err(RepositoryError::Unavailable(
+                    "pool timed out while waiting for an open connection".to_string(),
+                )))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_item(1, Uuid::now_v7())
+                .await
+                .expect_err("Get item did not fail");
+
+            assert_eq!(result, ApplicationError::Unavailable);
+        }
+    }
+
+    mod get_items_tests {
+        use super::*;
+        use chrono::Utc;
+        use paidy_submission::domain::item::Item;
+        use paidy_submission::domain::item_factory::MockItemFactory;
+        use paidy_submission::domain::item_query::{ItemCursor, ItemPage, ItemQuery};
+
+        #[tokio::test]
+        async fn should_get_multiple_items() {
+            let table_id = 1;
+            let first_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "first".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let second_item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "second".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_table()
+                .return_const(Ok(ItemPage {
+                    items: vec![first_item.clone(), second_item.clone()],
+                    next: None,
+                }))
+                .with(eq(table_id), eq(ItemQuery::default()))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_items(table_id, ItemQuery::default())
+                .await
+                .expect("Failed to get items");
+
+            assert_eq!(result.items.len(), 2);
+            assert_eq!(result.items[0].id, first_item.id);
+            assert_eq!(result.items[0].table_id, first_item.table_id);
+            assert_eq!(result.items[0].name, first_item.name);
+            assert_eq!(
+                result.items[0].preparation_time,
+                first_item.preparation_time
+            );
+
+            assert_eq!(result.items[1].id, second_item.id);
+            assert_eq!(result.items[1].table_id, second_item.table_id);
+            assert_eq!(result.items[1].name, second_item.name);
+            assert_eq!(
+                result.items[1].preparation_time,
+                second_item.preparation_time
+            );
+        }
+
+        #[tokio::test]
+        async fn should_get_single_items() {
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_table()
+                .return_const(Ok(ItemPage {
+                    items: vec![item.clone()],
+                    next: None,
+                }))
+                .with(eq(item.table_id), eq(ItemQuery::default()))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_items(item.table_id, ItemQuery::default())
+                .await
+                .expect("Failed to get items");
+
+            assert_eq!(result.items.len(), 1);
+            assert_eq!(result.items[0].id, item.id);
+            assert_eq!(result.items[0].table_id, item.table_id);
+            assert_eq!(result.items[0].name, item.name);
+            assert_eq!(result.items[0].preparation_time, item.preparation_time);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let table_id = 1;
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_table()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .with(eq(table_id), eq(ItemQuery::default()))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_items(table_id, ItemQuery::default())
+                .await
+                .expect_err("Get items did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+
+        #[tokio::test]
+        async fn should_get_empty_list_on_missing_items() {
+            let table_id = 1;
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_table()
+                .return_const(Ok(ItemPage::default()))
+                .with(eq(table_id), eq(ItemQuery::default()))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_items(table_id, ItemQuery::default())
+                .await
+                .expect("Failed to get items");
+
+            assert!(result.items.is_empty());
+        }
+
+        #[tokio::test]
+        async fn should_pass_query_and_next_cursor() {
+            let table_id = 1;
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "Pierogi".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+            let query = ItemQuery {
+                name: Some("pier".to_string()),
+                limit: 1,
+                ..ItemQuery::default()
+            };
+            let next = ItemCursor::from(&item);
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_table()
+                .with(eq(table_id), eq(query.clone()))
+                .return_const(Ok(ItemPage {
+                    items: vec![item.clone()],
+                    next: Some(next.clone()),
+                }))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_items(table_id, query)
+                .await
+                .expect("Failed to get items");
+
+            assert_eq!(result.items.len(), 1);
+            assert_eq!(result.next, Some(next));
+        }
+
+        #[tokio::test]
+        async fn should_reject_invalid_limit() {
+            let mut repository = MockItemRepository::new();
+            repository.expect_find_items_by_table().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let query = ItemQuery {
+                limit: 0,
+                ..ItemQuery::default()
+            };
+
+            let result = service
+                .get_items(1, query)
+                .await
+                .expect_err("Validation did not fail");
+
+            assert!(matches!(result, ApplicationError::ValidationError(_)));
+        }
+
+        #[tokio::test]
+        async fn should_reject_empty_time_window() {
+            let mut repository = MockItemRepository::new();
+            repository.expect_find_items_by_table().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let now = Utc::now();
+            let query = ItemQuery {
+                prepared_after: Some(now),
+                prepared_before: Some(now),
+                ..ItemQuery::default()
+            };
+
+            let result = service
+                .get_items(1, query)
+                .await
+                .expect_err("Validation did not fail");
+
+            assert!(matches!(result, ApplicationError::ValidationError(_)));
+        }
+    }
+
+    mod get_station_items_tests {
+        use super::*;
+        use chrono::Utc;
+        use paidy_submission::domain::item_factory::MockItemFactory;
+
+        #[tokio::test]
+        async fn should_get_station_items() {
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Piwo".to_string(),
+                Utc::now(),
+                Station::Bar,
+            )
+            .expect("Failed to create item");
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_station()
+                .return_const(Ok(vec![item.clone()]))
+                .with(eq(Station::Bar))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_station_items(Station::Bar)
+                .await
+                .expect("Failed to get items");
+
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].id, item.id);
+            assert_eq!(result[0].station, Station::Bar);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_items_by_station()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .once();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .get_station_items(Station::Bar)
+                .await
+                .expect_err("Get station items did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+    }
+
+    mod create_batch_tests {
+        use super::*;
+        use chrono::Utc;
+        use paidy_submission::application::error_code::{ErrorCode, Violation};
+        use paidy_submission::application::item_service::{
+            BatchCreateItemModel, BatchCreateItemsCommand, BatchMode,
+        };
+        use paidy_submission::domain::item::ItemValidationError;
+        use paidy_submission::domain::item_factory::MockItemFactory;
+        use paidy_submission::domain::metrics::MockItemMetrics;
+
+        fn batch_item(table_id: i64, name: &str) -> Item {
+            Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                name.to_string(),
+                Utc::now(),
+                Station::Bar,
+            )
+            .expect("Failed to create item")
+        }
+
+        fn command(mode: BatchMode, entries: &[(i64, &str)]) -> BatchCreateItemsCommand {
+            BatchCreateItemsCommand {
+                mode,
+                items: entries
+                    .iter()
+                    .map(|(table_id, name)| BatchCreateItemModel {
+                        table_id: *table_id,
+                        name: name.to_string(),
+                        station: None,
+                    })
+                    .collect(),
+            }
+        }
+
+        #[tokio::test]
+        async fn should_save_batch_in_one_call_grouped_by_table() {
+            let first = batch_item(2, "Piwo");
+            let second = batch_item(1, "Wino");
+            let third = batch_item(2, "Woda");
+
+            let mut factory = MockItemFactory::new();
+            for item in [&first, &second, &third] {
+                factory
+                    .expect_try_create()
+                    .with(eq(item.table_id), eq(item.name.clone()), eq(None))
+                    .return_const(Ok(item.clone()))
+                    .once();
+            }
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_save_items()
+                .with(eq(vec![first.clone(), second.clone(), third.clone()]))
+                .return_const(Ok(()))
+                .once();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory));
+
+            let result = service
+                .create_batch(command(
+                    BatchMode::AllOrNothing,
+                    &[(2, "Piwo"), (1, "Wino"), (2, "Woda")],
+                ))
+                .await
+                .expect("Failed to create batch");
+
+            assert!(result.errors.is_empty());
+            assert_eq!(result.tables.len(), 2);
+            assert_eq!(result.tables[0].table_id, 1);
+            assert_eq!(result.tables[0].items[0].id, second.id);
+            assert_eq!(result.tables[1].table_id, 2);
+            assert_eq!(result.tables[1].items[0].id, first.id);
+            assert_eq!(result.tables[1].items[1].id, third.id);
+        }
+
+        #[tokio::test]
+        async fn should_count_created_items_per_table() {
+            let first = batch_item(2, "Piwo");
+            let second = batch_item(1, "Wino");
+            let third = batch_item(2, "Woda");
+
+            let mut factory = MockItemFactory::new();
+            for item in [&first, &second, &third] {
+                factory
+                    .expect_try_create()
+                    .with(eq(item.table_id), eq(item.name.clone()), eq(None))
+                    .return_const(Ok(item.clone()));
+            }
+
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().return_const(Ok(()));
+
+            let mut metrics = MockItemMetrics::new();
+            metrics
+                .expect_items_created()
+                .with(eq(1), eq(1))
+                .return_const(())
+                .once();
+            metrics
+                .expect_items_created()
+                .with(eq(2), eq(2))
+                .return_const(())
+                .once();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory))
+                .with_metrics(Arc::new(metrics));
+
+            service
+                .create_batch(command(
+                    BatchMode::AllOrNothing,
+                    &[(2, "Piwo"), (1, "Wino"), (2, "Woda")],
+                ))
+                .await
+                .expect("Failed to create batch");
+        }
+
+        #[tokio::test]
+        async fn should_reject_whole_batch_with_all_errors() {
+            let valid = batch_item(1, "Piwo");
+
+            let mut factory = MockItemFactory::new();
+            factory
+                .expect_try_create()
+                .with(eq(1), eq("Piwo".to_string()), eq(None))
+                .return_const(Ok(valid))
+                .once();
+            factory
+                .expect_try_create()
+                .with(eq(-1), eq("Wino".to_string()), eq(None))
+                .return_const(Err(ItemValidationError::NegativeTableId))
+                .once();
+            factory
+                .expect_try_create()
+                .with(eq(1), eq("".to_string()), eq(None))
+                .return_const(Err(ItemValidationError::EmptyName))
+                .once();
+
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory));
+
+            let result = service
+                .create_batch(command(
+                    BatchMode::AllOrNothing,
+                    &[(1, "Piwo"), (-1, "Wino"), (1, "")],
+                ))
+                .await
+                .expect_err("Create batch did not fail");
+
+            assert_eq!(
+                result,
+                ApplicationError::ValidationError(vec![
+                    Violation::new(ErrorCode::NegativeTableId, "Table id cannot be negative.")
+                        .with_field("items[1].table_id"),
+                    Violation::new(ErrorCode::EmptyName, "Name cannot be empty.")
+                        .with_field("items[2].name"),
+                ])
+            );
+        }
+
+        #[tokio::test]
+        async fn should_save_valid_items_in_partial_mode() {
+            let valid = batch_item(1, "Piwo");
+
+            let mut factory = MockItemFactory::new();
+            factory
+                .expect_try_create()
+                .with(eq(1), eq("".to_string()), eq(None))
+                .return_const(Err(ItemValidationError::EmptyName))
+                .once();
+            factory
+                .expect_try_create()
+                .with(eq(1), eq("Piwo".to_string()), eq(None))
+                .return_const(Ok(valid.clone()))
+                .once();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_save_items()
+                .with(eq(vec![valid.clone()]))
                 .return_const(Ok(()))
                 .once();
 
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory));
+
+            let result = service
+                .create_batch(command(BatchMode::Partial, &[(1, ""), (1, "Piwo")]))
+                .await
+                .expect("Failed to create batch");
+
+            assert_eq!(result.tables.len(), 1);
+            assert_eq!(result.tables[0].items[0].id, valid.id);
+            assert_eq!(result.errors.len(), 1);
+            assert_eq!(result.errors[0].index, 0);
+            assert_eq!(result.errors[0].table_id, 1);
+            assert_eq!(result.errors[0].message, "Name cannot be empty.");
+        }
+
+        #[tokio::test]
+        async fn should_reject_empty_batch() {
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
-            service
-                .delete_item(table_id, item_id)
+            let result = service
+                .create_batch(command(BatchMode::Partial, &[]))
+                .await
+                .expect_err("Create batch did not fail");
+
+            assert!(matches!(result, ApplicationError::ValidationError(_)));
+        }
+
+        #[tokio::test]
+        async fn should_reject_too_many_items_in_batch() {
+            let mut factory = MockItemFactory::new();
+            factory.expect_try_create().never();
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(factory)).with_max_items(1);
+
+            let result = service
+                .create_batch(command(BatchMode::Partial, &[(1, "Piwo"), (2, "Wino")]))
                 .await
-                .expect("Failed to delete item");
+                .expect_err("Create batch did not fail");
+
+            assert!(matches!(
+                result,
+                ApplicationError::ValidationError(violations)
+                    if violations[0].code == ErrorCode::TooManyItems
+            ));
         }
 
         #[tokio::test]
         async fn should_handle_repository_error() {
-            let table_id = 1;
-            let item_id = Uuid::now_v7();
+            let valid = batch_item(1, "Piwo");
+
+            let mut factory = MockItemFactory::new();
+            factory.expect_try_create().return_const(Ok(valid)).once();
+
             let mut repository = MockItemRepository::new();
             repository
-                .expect_delete_item()
-                .with(eq(table_id), eq(item_id))
+                .expect_save_items()
                 .return_const(Err(RepositoryError::InternalRepositoryError(
                     "Crash".to_string(),
                 )))
                 .once();
 
-            let service =
-                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory));
 
             let result = service
-                .delete_item(table_id, item_id)
+                .create_batch(command(BatchMode::Partial, &[(1, "Piwo")]))
                 .await
-                .expect_err("Repository delete did not fail");
+                .expect_err("Create batch did not fail");
 
             assert_eq!(result, ApplicationError::InternalError);
         }
     }
 
-    mod get_item_tests {
+    mod update_item_tests {
         use super::*;
-        use chrono::Utc;
-        use paidy_submission::domain::item::Item;
-        use paidy_submission::domain::item_factory::MockItemFactory;
+        use chrono::{Duration, SubsecRound, Utc};
+        use mockall::predicate::always;
+        use paidy_submission::application::item_service::UpdateItemCommand;
+        use paidy_submission::domain::item_factory::{
+            ItemFactory, ItemFactoryImpl, MockItemFactory,
+        };
+        use paidy_submission::domain::preparation_estimator::MockPreparationTimeEstimator;
+
+        fn pending_item(preparation_in: Duration) -> Item {
+            Item::try_new(
+                Uuid::now_v7(),
+                1,
+                "Pierogi".to_string(),
+                Utc::now().round_subsecs(6) + preparation_in,
+                Station::Grill,
+            )
+            .expect("Failed to create item")
+        }
 
         #[tokio::test]
-        async fn should_get_item() {
-            let item = Item::try_new(Uuid::now_v7(), 1, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+        async fn should_update_item() {
+            let item = pending_item(Duration::minutes(30));
+            let updated = Item {
+                name: "Pierogi ruskie".to_string(),
+                notes: Some("No onions".to_string()),
+                ..item.clone()
+            };
 
             let mut repository = MockItemRepository::new();
             repository
                 .expect_find_item()
-                .return_const(Ok(Some(item.clone())))
                 .with(eq(item.table_id), eq(item.id))
+                .return_const(Ok(Some(item.clone())))
+                .once();
+            repository
+                .expect_update_item()
+                .with(eq(updated.clone()), always())
+                .return_const(Ok(Some(updated.clone())))
                 .once();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: Some("Pierogi ruskie".to_string()),
+                notes: Some(Some("No onions".to_string())),
+            };
             let result = service
-                .get_item(item.table_id, item.id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect("Failed to get item");
+                .expect("Failed to update item");
 
-            assert_eq!(result.id, item.id);
-            assert_eq!(result.table_id, item.table_id);
-            assert_eq!(result.name, item.name);
+            assert_eq!(result.name, updated.name);
+            assert_eq!(result.notes, updated.notes);
             assert_eq!(result.preparation_time, item.preparation_time);
         }
 
         #[tokio::test]
-        async fn should_get_resource_not_found_on_missing_item() {
-            let item = Item::try_new(Uuid::now_v7(), 1, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+        async fn should_clear_notes() {
+            let item = pending_item(Duration::minutes(30))
+                .with_notes(Some("No onions".to_string()))
+                .expect("Failed to add notes");
+            let updated = Item {
+                notes: None,
+                ..item.clone()
+            };
 
             let mut repository = MockItemRepository::new();
             repository
                 .expect_find_item()
-                .return_const(Ok(None))
-                .with(eq(item.table_id), eq(item.id))
+                .return_const(Ok(Some(item.clone())))
+                .once();
+            repository
+                .expect_update_item()
+                .with(eq(updated.clone()), always())
+                .return_const(Ok(Some(updated.clone())))
                 .once();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: None,
+                notes: Some(None),
+            };
             let result = service
-                .get_item(item.table_id, item.id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect_err("Get item did not fail");
+                .expect("Failed to update item");
 
-            assert_eq!(result, ApplicationError::ResourceNotFound);
+            assert_eq!(result.name, item.name);
+            assert_eq!(result.notes, None);
         }
 
         #[tokio::test]
-        async fn should_handle_repository_error() {
-            let item = Item::try_new(Uuid::now_v7(), 1, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+        async fn should_reject_empty_command() {
+            let mut repository = MockItemRepository::new();
+            repository.expect_find_item().never();
+            repository.expect_update_item().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let result = service
+                .update_item(1, Uuid::now_v7(), UpdateItemCommand::default())
+                .await
+                .expect_err("Update item did not fail");
+
+            assert!(matches!(result, ApplicationError::ValidationError(_)));
+        }
+
+        #[tokio::test]
+        async fn should_reject_empty_name() {
+            let item = pending_item(Duration::minutes(30));
 
             let mut repository = MockItemRepository::new();
             repository
                 .expect_find_item()
-                .return_const(Err(RepositoryError::InternalRepositoryError(
-                    "Crash".to_string(),
-                )))
-                .with(eq(item.table_id), eq(item.id))
+                .return_const(Ok(Some(item.clone())))
                 .once();
+            repository.expect_update_item().never();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: Some("".to_string()),
+                notes: None,
+            };
             let result = service
-                .get_item(item.table_id, item.id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect_err("Get item did not fail");
+                .expect_err("Update item did not fail");
 
-            assert_eq!(result, ApplicationError::InternalError);
+            assert!(matches!(result, ApplicationError::ValidationError(_)));
         }
-    }
-
-    mod get_items_tests {
-        use super::*;
-        use chrono::Utc;
-        use paidy_submission::domain::item::Item;
-        use paidy_submission::domain::item_factory::MockItemFactory;
 
         #[tokio::test]
-        async fn should_get_multiple_items() {
-            let table_id = 1;
-            let first_item = Item::try_new(Uuid::now_v7(), 1, "first".to_string(), Utc::now())
-                .expect("Failed to create item");
+        async fn should_get_resource_not_found_on_missing_item() {
+            let mut repository = MockItemRepository::new();
+            repository.expect_find_item().return_const(Ok(None)).once();
+            repository.expect_update_item().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+
+            let command = UpdateItemCommand {
+                name: Some("Pierogi".to_string()),
+                notes: None,
+            };
+            let result = service
+                .update_item(1, Uuid::now_v7(), command)
+                .await
+                .expect_err("Update item did not fail");
 
-            let second_item = Item::try_new(Uuid::now_v7(), 1, "second".to_string(), Utc::now())
+            assert_eq!(result, ApplicationError::ResourceNotFound);
+        }
+
+        #[tokio::test]
+        async fn should_update_item_created_with_random_preparation_time() {
+            let item = ItemFactoryImpl::default()
+                .try_create(1, "Pierogi".to_string(), None)
                 .expect("Failed to create item");
+            let updated = Item {
+                notes: Some("No onions".to_string()),
+                ..item.clone()
+            };
 
             let mut repository = MockItemRepository::new();
             repository
-                .expect_find_items_by_table()
-                .return_const(Ok(vec![first_item.clone(), second_item.clone()]))
-                .with(eq(table_id))
+                .expect_find_item()
+                .return_const(Ok(Some(item.clone())))
+                .once();
+            repository
+                .expect_update_item()
+                .withf(move |_, prepared_after| *prepared_after < item.preparation_time)
+                .return_const(Ok(Some(updated.clone())))
                 .once();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: None,
+                notes: Some(Some("No onions".to_string())),
+            };
             let result = service
-                .get_items(table_id)
+                .update_item(1, Uuid::now_v7(), command)
                 .await
-                .expect("Failed to get items");
-
-            assert_eq!(result.len(), 2);
-            assert_eq!(result[0].id, first_item.id);
-            assert_eq!(result[0].table_id, first_item.table_id);
-            assert_eq!(result[0].name, first_item.name);
-            assert_eq!(result[0].preparation_time, first_item.preparation_time);
+                .expect("Failed to update item");
 
-            assert_eq!(result[1].id, second_item.id);
-            assert_eq!(result[1].table_id, second_item.table_id);
-            assert_eq!(result[1].name, second_item.name);
-            assert_eq!(result[1].preparation_time, second_item.preparation_time);
+            assert_eq!(result.notes, Some("No onions".to_string()));
         }
 
         #[tokio::test]
-        async fn should_get_single_items() {
-            let item = Item::try_new(Uuid::now_v7(), 1, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+        async fn should_reject_item_past_preparation_time() {
+            let item = pending_item(Duration::minutes(-1));
 
             let mut repository = MockItemRepository::new();
             repository
-                .expect_find_items_by_table()
-                .return_const(Ok(vec![item.clone()]))
-                .with(eq(item.table_id))
+                .expect_find_item()
+                .return_const(Ok(Some(item.clone())))
                 .once();
+            repository.expect_update_item().never();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: Some("Pierogi ruskie".to_string()),
+                notes: None,
+            };
             let result = service
-                .get_items(item.table_id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect("Failed to get items");
+                .expect_err("Update item did not fail");
 
-            assert_eq!(result.len(), 1);
-            assert_eq!(result[0].id, item.id);
-            assert_eq!(result[0].table_id, item.table_id);
-            assert_eq!(result[0].name, item.name);
-            assert_eq!(result[0].preparation_time, item.preparation_time);
+            assert!(matches!(result, ApplicationError::Conflict(_)));
         }
 
         #[tokio::test]
-        async fn should_handle_repository_error() {
-            let table_id = 1;
+        async fn should_reject_item_in_preparation() {
+            let item = pending_item(Duration::minutes(5));
 
             let mut repository = MockItemRepository::new();
             repository
-                .expect_find_items_by_table()
-                .return_const(Err(RepositoryError::InternalRepositoryError(
-                    "Crash".to_string(),
-                )))
-                .with(eq(table_id))
+                .expect_find_item()
+                .return_const(Ok(Some(item.clone())))
                 .once();
+            repository.expect_update_item().never();
 
             let service =
-                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()))
+                    .with_estimator(Arc::new(MockPreparationTimeEstimator::new()));
 
+            let command = UpdateItemCommand {
+                name: Some("Pierogi ruskie".to_string()),
+                notes: None,
+            };
             let result = service
-                .get_items(table_id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect_err("Get items did not fail");
+                .expect_err("Update item did not fail");
 
-            assert_eq!(result, ApplicationError::InternalError);
+            assert!(matches!(result, ApplicationError::Conflict(_)));
         }
 
         #[tokio::test]
-        async fn should_get_empty_list_on_missing_items() {
-            let table_id = 1;
+        async fn should_reject_item_started_concurrently() {
+            let item = pending_item(Duration::minutes(30));
 
             let mut repository = MockItemRepository::new();
             repository
-                .expect_find_items_by_table()
-                .return_const(Ok(vec![]))
-                .with(eq(table_id))
+                .expect_find_item()
+                .return_const(Ok(Some(item.clone())))
+                .once();
+            repository
+                .expect_update_item()
+                .return_const(Ok(None))
                 .once();
 
             let service =
                 ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
 
+            let command = UpdateItemCommand {
+                name: Some("Pierogi ruskie".to_string()),
+                notes: None,
+            };
             let result = service
-                .get_items(table_id)
+                .update_item(item.table_id, item.id, command)
                 .await
-                .expect("Failed to get items");
+                .expect_err("Update item did not fail");
 
-            assert!(result.is_empty());
+            assert!(matches!(result, ApplicationError::Conflict(_)));
         }
     }
 
     mod create_items_test {
         use super::*;
         use chrono::{Duration, Utc};
+        use paidy_submission::application::error_code::{ErrorCode, Violation};
         use paidy_submission::domain::item::{Item, ItemValidationError};
         use paidy_submission::domain::item_factory::MockItemFactory;
+        use paidy_submission::domain::preparation_estimator::MockPreparationTimeEstimator;
 
         #[tokio::test]
         async fn should_create_single_item() {
             let table_id = 1;
-            let item = Item::try_new(Uuid::now_v7(), table_id, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
 
             let mut factory = MockItemFactory::new();
             factory
                 .expect_try_create()
-                .with(eq(item.table_id), eq(item.name.clone()))
+                .with(eq(item.table_id), eq(item.name.clone()), eq(None))
                 .return_const(Ok(item.clone()))
                 .once();
 
@@ -300,28 +1111,42 @@ mod item_service_tests {
         #[tokio::test]
         async fn should_save_multiple_items() {
             let table_id = 1;
-            let first_item =
-                Item::try_new(Uuid::now_v7(), table_id, "first".to_string(), Utc::now())
-                    .expect("Failed to create item");
+            let first_item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "first".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
 
             let second_item = Item::try_new(
                 Uuid::now_v7(),
                 table_id,
                 "second".to_string(),
                 Utc::now() + Duration::minutes(5),
+                Station::Grill,
             )
             .expect("Failed to create item");
 
             let mut factory = MockItemFactory::new();
             factory
                 .expect_try_create()
-                .with(eq(first_item.table_id), eq(first_item.name.clone()))
+                .with(
+                    eq(first_item.table_id),
+                    eq(first_item.name.clone()),
+                    eq(None),
+                )
                 .return_const(Ok(first_item.clone()))
                 .once();
 
             factory
                 .expect_try_create()
-                .with(eq(second_item.table_id), eq(second_item.name.clone()))
+                .with(
+                    eq(second_item.table_id),
+                    eq(second_item.name.clone()),
+                    eq(None),
+                )
                 .return_const(Ok(second_item.clone()))
                 .once();
 
@@ -393,16 +1218,100 @@ mod item_service_tests {
             assert!(matches!(result, ApplicationError::ValidationError(_)));
         }
 
+        #[tokio::test]
+        async fn should_report_every_invalid_item() {
+            let mut factory = MockItemFactory::new();
+            factory
+                .expect_try_create()
+                .with(eq(-1), eq("Piwo".to_string()), eq(None))
+                .return_const(Err(ItemValidationError::NegativeTableId))
+                .times(2);
+            factory
+                .expect_try_create()
+                .with(eq(-1), eq("".to_string()), eq(None))
+                .return_const(Err(ItemValidationError::EmptyName))
+                .once();
+
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory));
+            let command = CreateItemsCommand {
+                items: ["Piwo", "", "Piwo"]
+                    .map(|name| CreateItemModel {
+                        name: name.to_string(),
+                        station: None,
+                    })
+                    .into(),
+            };
+
+            let result = service
+                .create_items(-1, command)
+                .await
+                .expect_err("Validation did not fail");
+
+            assert_eq!(
+                result,
+                ApplicationError::ValidationError(vec![
+                    Violation::new(ErrorCode::NegativeTableId, "Table id cannot be negative.")
+                        .with_field("table_id"),
+                    Violation::new(ErrorCode::EmptyName, "Name cannot be empty.")
+                        .with_field("items[1].name"),
+                ])
+            );
+        }
+
+        #[tokio::test]
+        async fn should_reject_too_many_items() {
+            let mut factory = MockItemFactory::new();
+            factory.expect_try_create().never();
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
+            let service =
+                ItemServiceImpl::new(Arc::new(repository), Arc::new(factory)).with_max_items(2);
+            let command = CreateItemsCommand {
+                items: ["Piwo", "Wino", "Woda"]
+                    .map(|name| CreateItemModel {
+                        name: name.to_string(),
+                        station: None,
+                    })
+                    .into(),
+            };
+
+            let result = service
+                .create_items(1, command)
+                .await
+                .expect_err("Validation did not fail");
+
+            assert_eq!(
+                result,
+                ApplicationError::invalid(
+                    Violation::new(
+                        ErrorCode::TooManyItems,
+                        "At most 2 items can be created at once."
+                    )
+                    .with_field("items")
+                )
+            );
+        }
+
         #[tokio::test]
         async fn should_handle_repository_error() {
             let table_id = 1;
-            let item = Item::try_new(Uuid::now_v7(), table_id, "name".to_string(), Utc::now())
-                .expect("Failed to create item");
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
 
             let mut factory = MockItemFactory::new();
             factory
                 .expect_try_create()
-                .with(eq(item.table_id), eq(item.name.clone()))
+                .with(eq(item.table_id), eq(item.name.clone()), eq(None))
                 .return_const(Ok(item.clone()))
                 .once();
 
@@ -425,6 +1334,98 @@ mod item_service_tests {
 
             assert_eq!(result, ApplicationError::InternalError);
         }
+
+        #[tokio::test]
+        async fn should_use_estimated_preparation_time() {
+            let table_id = 1;
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+            let estimated = Item {
+                preparation_time: item.preparation_time + Duration::minutes(30),
+                ..item.clone()
+            };
+
+            let mut factory = MockItemFactory::new();
+            factory
+                .expect_try_create()
+                .with(eq(item.table_id), eq(item.name.clone()), eq(None))
+                .return_const(Ok(item.clone()))
+                .once();
+
+            let mut estimator = MockPreparationTimeEstimator::new();
+            estimator
+                .expect_estimate()
+                .with(eq(vec![item.clone()]))
+                .return_const(Ok(vec![estimated.clone()]))
+                .once();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_save_items()
+                .with(eq(vec![estimated.clone()]))
+                .return_const(Ok(()))
+                .once();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory))
+                .with_estimator(Arc::new(estimator));
+            let command = CreateItemsCommandExt::from_item(item.clone());
+
+            let result = service
+                .create_items(table_id, command)
+                .await
+                .expect("Failed to save items");
+
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].id, item.id);
+            assert_eq!(result[0].preparation_time, estimated.preparation_time);
+        }
+
+        #[tokio::test]
+        async fn should_handle_estimator_error() {
+            let table_id = 1;
+            let item = Item::try_new(
+                Uuid::now_v7(),
+                table_id,
+                "name".to_string(),
+                Utc::now(),
+                Station::Grill,
+            )
+            .expect("Failed to create item");
+
+            let mut factory = MockItemFactory::new();
+            factory
+                .expect_try_create()
+                .return_const(Ok(item.clone()))
+                .once();
+
+            let mut estimator = MockPreparationTimeEstimator::new();
+            estimator
+                .expect_estimate()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .once();
+
+            let mut repository = MockItemRepository::new();
+            repository.expect_save_items().never();
+
+            let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(factory))
+                .with_estimator(Arc::new(estimator));
+            let command = CreateItemsCommandExt::from_item(item.clone());
+
+            let result = service
+                .create_items(table_id, command)
+                .await
+                .expect_err("Estimator did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
     }
 }
 
@@ -435,13 +1436,17 @@ impl CreateItemsCommandExt {
         CreateItemsCommand {
             items: vec![CreateItemModel {
                 name: "".to_string(),
+                station: None,
             }],
         }
     }
 
     pub fn from_item(item: Item) -> CreateItemsCommand {
         CreateItemsCommand {
-            items: vec![CreateItemModel { name: item.name }],
+            items: vec![CreateItemModel {
+                name: item.name,
+                station: None,
+            }],
         }
     }
 
@@ -449,7 +1454,10 @@ impl CreateItemsCommandExt {
         CreateItemsCommand {
             items: items
                 .into_iter()
-                .map(|item| CreateItemModel { name: item.name })
+                .map(|item| CreateItemModel {
+                    name: item.name,
+                    station: None,
+                })
                 .collect(),
         }
     }
@@ -0,0 +1,155 @@
+mod limits_middleware_tests {
+    use axum::body::{to_bytes, Body};
+    use axum::extract::DefaultBodyLimit;
+    use axum::http::header::{CONTENT_TYPE, RETRY_AFTER};
+    use axum::http::{Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::response::Response;
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use axum_extra::extract::WithRejection;
+    use paidy_submission::infrastructure::metrics::PrometheusMetrics;
+    use paidy_submission::server::middleware::{
+        LimitsConfiguration, LoadSheddingMiddleware, MetricsMiddleware, TimeoutMiddleware,
+    };
+    use paidy_submission::web::errors::ServerError;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Semaphore;
+    use tower::ServiceExt;
+
+    async fn slow() -> &'static str {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        "done"
+    }
+
+    fn timeout_router(limits: LimitsConfiguration) -> Router {
+        Router::new()
+            .route("/v1/slow", get(slow))
+            .route("/v1/patient", get(slow))
+            .layer(from_fn_with_state(
+                Arc::new(limits),
+                TimeoutMiddleware::enforce,
+            ))
+    }
+
+    fn limits() -> LimitsConfiguration {
+        LimitsConfiguration {
+            request_timeout: Duration::from_millis(20),
+            route_timeouts: HashMap::from([("/v1/patient".to_string(), Duration::from_secs(5))]),
+            ..LimitsConfiguration::default()
+        }
+    }
+
+    async fn get_request(router: Router, uri: &str) -> Response {
+        router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn json_body(response: Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_time_out_slow_requests() {
+        let response = get_request(timeout_router(limits()), "/v1/slow").await;
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(
+            json_body(response).await,
+            json!({"code": "request_timeout", "message": "Request timed out."})
+        );
+    }
+
+    #[tokio::test]
+    async fn should_apply_route_timeout() {
+        let response = get_request(timeout_router(limits()), "/v1/patient").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_shed_requests_over_the_limit() {
+        let permits = Arc::new(Semaphore::new(1));
+        let router = Router::new()
+            .route("/v1/items", get(|| async { "ok" }))
+            .layer(from_fn_with_state(
+                permits.clone(),
+                LoadSheddingMiddleware::shed,
+            ));
+
+        let in_flight = permits.clone().try_acquire_owned().unwrap();
+        let response = get_request(router.clone(), "/v1/items").await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "5");
+        assert_eq!(
+            json_body(response).await,
+            json!({"code": "overloaded", "message": "Server is overloaded."})
+        );
+
+        drop(in_flight);
+        let response = get_request(router, "/v1/items").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_reject_large_bodies() {
+        let router = Router::new()
+            .route(
+                "/v1/items",
+                post(
+                    |WithRejection(Json(body), _): WithRejection<Json<Value>, ServerError>| async move {
+                        body.to_string()
+                    },
+                ),
+            )
+            .layer(DefaultBodyLimit::max(16));
+
+        let response = router
+            .oneshot(
+                Request::post("/v1/items")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(json!({"name": "a".repeat(32)}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            json_body(response).await,
+            json!({"code": "payload_too_large", "message": "Request body is too large."})
+        );
+    }
+
+    #[tokio::test]
+    async fn should_count_rejections_per_route_and_reason() {
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let router = timeout_router(limits())
+            .route(
+                "/v1/busy",
+                get(|| async { Err::<(), _>(ServerError::Overloaded) }),
+            )
+            .layer(from_fn_with_state(
+                metrics.clone(),
+                MetricsMiddleware::track,
+            ));
+
+        get_request(router.clone(), "/v1/slow").await;
+        get_request(router, "/v1/busy").await;
+
+        let output = metrics.render();
+        assert!(output.contains(
+            "http_requests_rejected_total{route=\"/v1/slow\",reason=\"request_timeout\"} 1"
+        ));
+        assert!(output
+            .contains("http_requests_rejected_total{route=\"/v1/busy\",reason=\"overloaded\"} 1"));
+    }
+}
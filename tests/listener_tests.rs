@@ -0,0 +1,124 @@
+mod listener_tests {
+    use axum::routing::get;
+    use axum::Router;
+    use paidy_submission::infrastructure::metrics::PrometheusMetrics;
+    use paidy_submission::server::admin::{AdminRouter, HealthProbe};
+    use paidy_submission::server::listener::UnixSocketListener;
+    use paidy_submission::server::shutdown::Shutdown;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixStream;
+
+    fn health_router() -> Router {
+        AdminRouter::create(
+            HealthProbe::new(Shutdown::new(), Duration::from_secs(1)),
+            Arc::new(PrometheusMetrics::new()),
+        )
+    }
+
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("paidy-{}-{}.sock", name, std::process::id()))
+    }
+
+    async fn send_get(path: &PathBuf, uri: &str) -> String {
+        let mut stream = UnixStream::connect(path)
+            .await
+            .expect("Failed to connect to socket");
+        let request = format!(
+            "GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+            uri
+        );
+        stream
+            .write_all(request.as_bytes())
+            .await
+            .expect("Failed to write request");
+
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .await
+            .expect("Failed to read response");
+        response
+    }
+
+    #[tokio::test]
+    async fn should_serve_router_on_unix_socket() {
+        let path = socket_path("serve");
+        let listener = UnixSocketListener::bind(&path).expect("Failed to bind socket");
+        tokio::spawn(UnixSocketListener::serve(
+            listener,
+            health_router(),
+            Shutdown::new(),
+        ));
+
+        let response = send_get(&path, "/health").await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        std::fs::remove_file(path).expect("Failed to remove socket");
+    }
+
+    #[tokio::test]
+    async fn should_replace_stale_unix_socket() {
+        let path = socket_path("stale");
+        drop(UnixSocketListener::bind(&path).expect("Failed to bind socket"));
+
+        let listener = UnixSocketListener::bind(&path).expect("Failed to rebind socket");
+        tokio::spawn(UnixSocketListener::serve(
+            listener,
+            health_router(),
+            Shutdown::new(),
+        ));
+
+        let response = send_get(&path, "/health").await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        std::fs::remove_file(path).expect("Failed to remove socket");
+    }
+
+    #[tokio::test]
+    async fn should_not_replace_regular_file() {
+        let path = socket_path("file");
+        std::fs::write(&path, "keep").expect("Failed to write file");
+
+        let result = UnixSocketListener::bind(&path);
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep");
+        std::fs::remove_file(path).expect("Failed to remove file");
+    }
+
+    #[tokio::test]
+    async fn should_drain_in_flight_requests_on_shutdown() {
+        let path = socket_path("drain");
+        let listener = UnixSocketListener::bind(&path).expect("Failed to bind socket");
+        let router = Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                "done"
+            }),
+        );
+        let shutdown = Shutdown::new();
+        let server = tokio::spawn(UnixSocketListener::serve(
+            listener,
+            router,
+            shutdown.clone(),
+        ));
+
+        let request_path = path.clone();
+        let request = tokio::spawn(async move { send_get(&request_path, "/slow").await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        shutdown.trigger();
+
+        let response = request.await.expect("Request failed");
+        let stopped = tokio::time::timeout(Duration::from_secs(1), server).await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("done"));
+        assert!(matches!(stopped, Ok(Ok(Ok(())))));
+        assert!(UnixStream::connect(&path).await.is_err());
+        std::fs::remove_file(path).expect("Failed to remove socket");
+    }
+}
@@ -0,0 +1,127 @@
+mod logging_tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use axum::Router;
+    use paidy_submission::application::item_service::{
+        CreateItemModel, CreateItemsCommand, ItemService, ItemServiceImpl,
+    };
+    use paidy_submission::application::payload_logging::PayloadLogging;
+    use paidy_submission::domain::item::ItemValidationError;
+    use paidy_submission::domain::item_factory::MockItemFactory;
+    use paidy_submission::domain::repository::MockItemRepository;
+    use paidy_submission::server::middleware::{
+        RequestIdConfiguration, RequestIdMiddleware, TraceMiddleware,
+    };
+    use serde_json::Value;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use tracing::subscriber::DefaultGuard;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn lines(&self) -> Vec<Value> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).expect("Log line is not JSON"))
+                .collect()
+        }
+    }
+
+    fn capture() -> (Buffer, DefaultGuard) {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(move || writer.clone()),
+        );
+        (buffer, tracing::subscriber::set_default(subscriber))
+    }
+
+    #[tokio::test]
+    async fn should_log_request_fields_as_json() {
+        let (buffer, _guard) = capture();
+        let router = Router::new()
+            .route("/tables/:table_id/items", get(|| async { StatusCode::OK }))
+            .layer(TraceMiddleware::create())
+            .layer(from_fn_with_state(
+                Arc::new(RequestIdConfiguration::default()),
+                RequestIdMiddleware::handle,
+            ));
+
+        let response = router
+            .oneshot(
+                Request::get("/tables/1/items")
+                    .header("x-request-id", "a1b2c3")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        drop(response);
+
+        let lines = buffer.lines();
+        let finished = lines
+            .iter()
+            .find(|line| line["fields"]["message"] == "Finished processing request")
+            .expect("Request was not logged");
+        let span = &finished["span"];
+
+        assert_eq!(span["request_id"], "a1b2c3");
+        assert_eq!(span["method"], "GET");
+        assert_eq!(span["route"], "/tables/:table_id/items");
+        assert_eq!(span["status"], 200);
+        assert!(span["latency_ms"].is_u64());
+    }
+
+    #[tokio::test]
+    async fn should_redact_command_payload() {
+        let (buffer, _guard) = capture();
+        let mut factory = MockItemFactory::new();
+        factory
+            .expect_try_create()
+            .returning(|_, _, _| Err(ItemValidationError::EmptyName));
+        let service = ItemServiceImpl::new(Arc::new(MockItemRepository::new()), Arc::new(factory))
+            .with_payload_logging(PayloadLogging::Redacted);
+
+        let _ = service
+            .create_items(
+                1,
+                CreateItemsCommand {
+                    items: vec![CreateItemModel {
+                        name: "Secret recipe".to_string(),
+                        station: None,
+                    }],
+                },
+            )
+            .await;
+
+        let lines = buffer.lines();
+        let messages = lines
+            .iter()
+            .map(|line| line["fields"]["message"].as_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        assert!(messages.contains(&"Creating items from command: CreateItemsCommand { items: 1 }"));
+        assert!(!messages
+            .iter()
+            .any(|message| message.contains("Secret recipe")));
+    }
+}
@@ -0,0 +1,45 @@
+mod metrics_middleware_tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use axum::Router;
+    use paidy_submission::infrastructure::metrics::PrometheusMetrics;
+    use paidy_submission::server::middleware::MetricsMiddleware;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn router(metrics: Arc<PrometheusMetrics>) -> Router {
+        let api = Router::new().route("/tables/:table_id/items", get(|| async { StatusCode::OK }));
+        Router::new()
+            .nest("/v1/", api)
+            .layer(from_fn_with_state(metrics, MetricsMiddleware::track))
+    }
+
+    async fn send(router: Router, uri: &str) {
+        router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_record_requests_by_route_template_and_status() {
+        let metrics = Arc::new(PrometheusMetrics::new());
+
+        send(router(metrics.clone()), "/v1/tables/1/items").await;
+        send(router(metrics.clone()), "/v1/tables/2/items").await;
+        send(router(metrics.clone()), "/v1/unknown").await;
+        let output = metrics.render();
+
+        assert!(output.contains(
+            "http_requests_total{method=\"GET\",route=\"/v1/tables/:table_id/items\",status=\"200\"} 2"
+        ));
+        assert!(output
+            .contains("http_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1"));
+        assert!(output.contains(
+            "http_request_duration_seconds_count{method=\"GET\",route=\"/v1/tables/:table_id/items\",status=\"200\"} 2"
+        ));
+        assert!(!output.contains("/v1/tables/1/items"));
+    }
+}
@@ -0,0 +1,107 @@
+mod overdue_router_tests {
+    use chrono::DateTime;
+    use paidy_submission::application::item_service::{ApplicationError, ItemModel};
+    use paidy_submission::application::overdue_service::MockOverdueService;
+    use paidy_submission::domain::item::{ItemStatus, OverduePolicy};
+    use paidy_submission::domain::station::Station;
+    use paidy_submission::web::overdue_endpoint::OverdueRouter;
+    use reqwest::Client;
+    use std::net::SocketAddr;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use tokio::net::TcpListener;
+    use uuid::Uuid;
+
+    const RAW_OVERDUE_ITEMS: &str = r#"{"items":[{"id":"01935dfe-97cf-73b2-be4c-15b3acfc607e","table_id":1,"name":"Pierogi","preparation_time":"2024-11-24T00:00:00Z","station":"grill","status":"pending","remaining_seconds":0,"is_overdue":true}]}"#;
+    const RAW_INTERNAL_ERROR: &str =
+        r#"{"code":"internal_error","message":"Internal server error."}"#;
+
+    struct OverdueRouterTestContext {
+        client: Client,
+        address: SocketAddr,
+    }
+
+    impl OverdueRouterTestContext {
+        async fn new(service: MockOverdueService) -> Self {
+            let listener = TcpListener::bind("0.0.0.0:0")
+                .await
+                .expect("Failed to bind port");
+
+            let address = listener.local_addr().expect("Failed to get local address");
+
+            let router = OverdueRouter::create(Arc::new(service), OverduePolicy::default());
+
+            tokio::spawn(async move {
+                axum::serve(listener, router).await.expect("Error");
+            });
+
+            let client = Client::new();
+
+            Self { address, client }
+        }
+    }
+
+    #[tokio::test]
+    async fn should_get_overdue_items() {
+        let model = ItemModel {
+            id: Uuid::parse_str("01935dfe-97cf-73b2-be4c-15b3acfc607e")
+                .expect("Failed to parse UUID"),
+            table_id: 1,
+            name: "Pierogi".to_string(),
+            preparation_time: DateTime::from_str("2024-11-24T00:00:00Z")
+                .expect("Failed to parse date"),
+            station: Station::Grill,
+            status: ItemStatus::Pending,
+            notes: None,
+        };
+
+        let mut service = MockOverdueService::new();
+        service
+            .expect_get_overdue_items()
+            .return_const(Ok(vec![model]))
+            .once();
+
+        let context = OverdueRouterTestContext::new(service).await;
+
+        let url = format!("http://{}/items/overdue", context.address);
+        let response = context
+            .client
+            .get(url)
+            .send()
+            .await
+            .expect("Failed to get response");
+
+        let status = response.status();
+
+        let body = response.text().await.expect("Failed to get body");
+
+        assert_eq!(status, 200);
+        assert_eq!(body, RAW_OVERDUE_ITEMS);
+    }
+
+    #[tokio::test]
+    async fn should_handle_service_error() {
+        let mut service = MockOverdueService::new();
+        service
+            .expect_get_overdue_items()
+            .return_const(Err(ApplicationError::InternalError))
+            .once();
+
+        let context = OverdueRouterTestContext::new(service).await;
+
+        let url = format!("http://{}/items/overdue", context.address);
+        let response = context
+            .client
+            .get(url)
+            .send()
+            .await
+            .expect("Failed to get response");
+
+        let status = response.status();
+
+        let body = response.text().await.expect("Failed to get body");
+
+        assert_eq!(status, 500);
+        assert_eq!(body, RAW_INTERNAL_ERROR);
+    }
+}
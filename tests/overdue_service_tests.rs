@@ -0,0 +1,263 @@
+use chrono::{Duration, Utc};
+use paidy_submission::application::item_service::ApplicationError;
+use paidy_submission::application::overdue_service::{OverdueService, OverdueServiceImpl};
+use paidy_submission::domain::events::{ItemEvent, MockEventPublisher, PublishError};
+use paidy_submission::domain::item::{Item, OverduePolicy};
+use paidy_submission::domain::repository::{MockItemRepository, RepositoryError};
+use paidy_submission::domain::station::Station;
+use paidy_submission::infrastructure::event_publisher::BroadcastEventPublisher;
+use std::sync::Arc;
+use uuid::Uuid;
+
+mod overdue_service_tests {
+    use super::*;
+    use mockall::predicate::eq;
+
+    const OVERDUE: OverduePolicy = OverduePolicy::new(std::time::Duration::from_secs(60));
+
+    fn overdue_item() -> Item {
+        Item::try_new(
+            Uuid::now_v7(),
+            1,
+            "Pierogi".to_string(),
+            Utc::now() - Duration::minutes(5),
+            Station::Grill,
+        )
+        .expect("Failed to create item")
+    }
+
+    mod get_overdue_items_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn should_get_overdue_items() {
+            let item = overdue_item();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_overdue_items()
+                .withf(|before| *before <= Utc::now() - Duration::seconds(60))
+                .return_const(Ok(vec![item.clone()]))
+                .once();
+
+            let service = OverdueServiceImpl::new(
+                Arc::new(repository),
+                Arc::new(MockEventPublisher::new()),
+                OVERDUE,
+            );
+
+            let result = service
+                .get_overdue_items()
+                .await
+                .expect("Failed to get overdue items");
+
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].id, item.id);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_overdue_items()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .once();
+
+            let service = OverdueServiceImpl::new(
+                Arc::new(repository),
+                Arc::new(MockEventPublisher::new()),
+                OVERDUE,
+            );
+
+            let result = service
+                .get_overdue_items()
+                .await
+                .expect_err("Get overdue items did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+    }
+
+    mod notify_overdue_items_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn should_publish_event_for_each_newly_overdue_item() {
+            let first_item = overdue_item();
+            let second_item = overdue_item();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Ok(vec![first_item.clone(), second_item.clone()]))
+                .once();
+            repository
+                .expect_mark_overdue_notified()
+                .with(eq(vec![first_item.id, second_item.id]))
+                .return_const(Ok(()))
+                .once();
+
+            let mut publisher = MockEventPublisher::new();
+            publisher
+                .expect_publish()
+                .with(eq(ItemEvent::overdue(&first_item)))
+                .return_const(Ok(()))
+                .once();
+            publisher
+                .expect_publish()
+                .with(eq(ItemEvent::overdue(&second_item)))
+                .return_const(Ok(()))
+                .once();
+
+            let service =
+                OverdueServiceImpl::new(Arc::new(repository), Arc::new(publisher), OVERDUE);
+
+            let result = service
+                .notify_overdue_items()
+                .await
+                .expect("Failed to notify overdue items");
+
+            assert_eq!(result, 2);
+        }
+
+        #[tokio::test]
+        async fn should_deliver_events_to_subscribers() {
+            let item = overdue_item();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Ok(vec![item.clone()]))
+                .once();
+            repository
+                .expect_mark_overdue_notified()
+                .with(eq(vec![item.id]))
+                .return_const(Ok(()))
+                .once();
+
+            let publisher = Arc::new(BroadcastEventPublisher::new(16));
+            let mut receiver = publisher.subscribe();
+
+            let service = OverdueServiceImpl::new(Arc::new(repository), publisher, OVERDUE);
+
+            service
+                .notify_overdue_items()
+                .await
+                .expect("Failed to notify overdue items");
+
+            let event = receiver.try_recv().expect("Failed to receive event");
+            assert_eq!(event, ItemEvent::overdue(&item));
+        }
+
+        #[tokio::test]
+        async fn should_only_mark_published_items() {
+            let first_item = overdue_item();
+            let second_item = overdue_item();
+
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Ok(vec![first_item.clone(), second_item.clone()]))
+                .once();
+            repository
+                .expect_mark_overdue_notified()
+                .with(eq(vec![first_item.id]))
+                .return_const(Ok(()))
+                .once();
+
+            let mut publisher = MockEventPublisher::new();
+            publisher
+                .expect_publish()
+                .with(eq(ItemEvent::overdue(&first_item)))
+                .return_const(Ok(()))
+                .once();
+            publisher
+                .expect_publish()
+                .with(eq(ItemEvent::overdue(&second_item)))
+                .return_const(Err(PublishError("item.overdue")))
+                .once();
+
+            let service =
+                OverdueServiceImpl::new(Arc::new(repository), Arc::new(publisher), OVERDUE);
+
+            let result = service
+                .notify_overdue_items()
+                .await
+                .expect_err("Notify overdue items did not fail");
+
+            assert_eq!(result, ApplicationError::Unavailable);
+        }
+
+        #[tokio::test]
+        async fn should_not_mark_items_without_subscribers() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Ok(vec![overdue_item()]))
+                .once();
+            repository.expect_mark_overdue_notified().never();
+
+            let service = OverdueServiceImpl::new(
+                Arc::new(repository),
+                Arc::new(BroadcastEventPublisher::new(16)),
+                OVERDUE,
+            );
+
+            let result = service
+                .notify_overdue_items()
+                .await
+                .expect_err("Notify overdue items did not fail");
+
+            assert_eq!(result, ApplicationError::Unavailable);
+        }
+
+        #[tokio::test]
+        async fn should_not_publish_without_overdue_items() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Ok(vec![]))
+                .once();
+            repository.expect_mark_overdue_notified().never();
+
+            let mut publisher = MockEventPublisher::new();
+            publisher.expect_publish().never();
+
+            let service =
+                OverdueServiceImpl::new(Arc::new(repository), Arc::new(publisher), OVERDUE);
+
+            let result = service
+                .notify_overdue_items()
+                .await
+                .expect("Failed to notify overdue items");
+
+            assert_eq!(result, 0);
+        }
+
+        #[tokio::test]
+        async fn should_handle_repository_error() {
+            let mut repository = MockItemRepository::new();
+            repository
+                .expect_find_unnotified_overdue_items()
+                .return_const(Err(RepositoryError::InternalRepositoryError(
+                    "Crash".to_string(),
+                )))
+                .once();
+
+            let mut publisher = MockEventPublisher::new();
+            publisher.expect_publish().never();
+
+            let service =
+                OverdueServiceImpl::new(Arc::new(repository), Arc::new(publisher), OVERDUE);
+
+            let result = service
+                .notify_overdue_items()
+                .await
+                .expect_err("Notify overdue items did not fail");
+
+            assert_eq!(result, ApplicationError::InternalError);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+mod problem_details_middleware_tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::header::{ACCEPT, CONTENT_TYPE};
+    use axum::http::{Request, StatusCode};
+    use axum::middleware::from_fn;
+    use axum::response::Response;
+    use axum::routing::get;
+    use axum::Router;
+    use paidy_submission::application::error_code::{ErrorCode, Violation};
+    use paidy_submission::server::middleware::ProblemDetailsMiddleware;
+    use paidy_submission::web::errors::ServerError;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    fn router() -> Router {
+        Router::new()
+            .route(
+                "/v1/items",
+                get(|| async {
+                    Err::<(), _>(ServerError::Invalid(vec![
+                        Violation::new(ErrorCode::EmptyName, "Name cannot be empty.")
+                            .with_field("items[0].name"),
+                        Violation::new(ErrorCode::NegativeTableId, "Table id cannot be negative.")
+                            .with_field("items[1].table_id"),
+                    ]))
+                }),
+            )
+            .route("/v1/ok", get(|| async { "ok" }))
+            .layer(from_fn(ProblemDetailsMiddleware::negotiate))
+    }
+
+    async fn send(uri: &str, accept: Option<&str>) -> Response {
+        let mut request = Request::get(uri);
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+        router()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn json_body(response: Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_render_problem_details_when_accepted() {
+        let response = send(
+            "/v1/items",
+            Some("application/json;q=0.5, application/problem+json"),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        assert_eq!(
+            json_body(response).await,
+            json!({
+                "type": "urn:paidy:error:validation_failed",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "Name cannot be empty. Table id cannot be negative.",
+                "instance": "/v1/items",
+                "code": "validation_failed",
+                "violations": [
+                    {"code": "empty_name", "field": "items[0].name", "message": "Name cannot be empty."},
+                    {"code": "negative_table_id", "field": "items[1].table_id", "message": "Table id cannot be negative."},
+                ],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_render_error_response_by_default() {
+        let response = send("/v1/items", Some("application/json")).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            json_body(response).await,
+            json!({
+                "code": "validation_failed",
+                "message": "Name cannot be empty. Table id cannot be negative.",
+                "violations": [
+                    {"code": "empty_name", "field": "items[0].name", "message": "Name cannot be empty."},
+                    {"code": "negative_table_id", "field": "items[1].table_id", "message": "Table id cannot be negative."},
+                ],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_leave_successful_responses_untouched() {
+        let response = send("/v1/ok", Some("application/problem+json")).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.as_ref(), b"ok");
+    }
+}
@@ -0,0 +1,160 @@
+mod request_id_middleware_tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{HeaderName, Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use axum::{Extension, Router};
+    use paidy_submission::server::middleware::{
+        InvalidRequestId, RequestIdConfiguration, RequestIdMiddleware,
+    };
+    use paidy_submission::web::errors::ServerError;
+    use paidy_submission::web::request_id::RequestId;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn router(config: RequestIdConfiguration) -> Router {
+        Router::new()
+            .route(
+                "/echo",
+                get(|Extension(request_id): Extension<RequestId>| async move { request_id.0 }),
+            )
+            .route(
+                "/missing",
+                get(|| async { Err::<(), _>(ServerError::NotFound) }),
+            )
+            .layer(from_fn_with_state(
+                Arc::new(config),
+                RequestIdMiddleware::handle,
+            ))
+    }
+
+    async fn send(
+        router: Router,
+        uri: &str,
+        header: Option<(&str, &str)>,
+    ) -> (StatusCode, Option<String>, String) {
+        let mut request = Request::get(uri);
+        if let Some((name, value)) = header {
+            request = request.header(name, value);
+        }
+        let response = router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let request_id = response
+            .headers()
+            .get("x-request-id")
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (
+            status,
+            request_id,
+            String::from_utf8(body.to_vec()).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_preserve_valid_request_id() {
+        let (status, request_id, body) = send(
+            router(RequestIdConfiguration::default()),
+            "/echo",
+            Some(("x-request-id", "gateway-1234.abc:5")),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(request_id.as_deref(), Some("gateway-1234.abc:5"));
+        assert_eq!(body, "gateway-1234.abc:5");
+    }
+
+    #[tokio::test]
+    async fn should_generate_missing_request_id() {
+        let (status, request_id, body) =
+            send(router(RequestIdConfiguration::default()), "/echo", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(request_id.as_deref(), Some(body.as_str()));
+        assert!(uuid::Uuid::parse_str(&body).is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_replace_malformed_and_oversized_request_ids() {
+        let oversized = "a".repeat(129);
+
+        for malformed in ["drop table;", "<script>", oversized.as_str()] {
+            let (status, request_id, body) = send(
+                router(RequestIdConfiguration::default()),
+                "/echo",
+                Some(("x-request-id", malformed)),
+            )
+            .await;
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(request_id.as_deref(), Some(body.as_str()));
+            assert!(uuid::Uuid::parse_str(&body).is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_request_id_when_configured() {
+        let config = RequestIdConfiguration {
+            invalid: InvalidRequestId::Reject,
+            ..RequestIdConfiguration::default()
+        };
+
+        let (status, request_id, body) =
+            send(router(config), "/echo", Some(("x-request-id", "not valid"))).await;
+
+        let request_id = request_id.expect("Missing request id");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body,
+            format!(
+                r#"{{"code":"malformed_request_id","message":"Malformed x-request-id header.","request_id":"{}"}}"#,
+                request_id
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn should_include_request_id_in_error_response() {
+        let (status, _, body) = send(
+            router(RequestIdConfiguration::default()),
+            "/missing",
+            Some(("x-request-id", "abc-123")),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            body,
+            r#"{"code":"not_found","message":"Resource not found","request_id":"abc-123"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn should_use_configured_header() {
+        let config = RequestIdConfiguration {
+            header: HeaderName::from_static("x-correlation-id"),
+            ..RequestIdConfiguration::default()
+        };
+
+        let response = router(config)
+            .oneshot(
+                Request::get("/echo")
+                    .header("x-correlation-id", "abc-123")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get("x-correlation-id").unwrap(),
+            "abc-123"
+        );
+        assert!(response.headers().get("x-request-id").is_none());
+    }
+}
@@ -0,0 +1,171 @@
+mod resilient_repository_tests {
+    use paidy_submission::domain::health::HealthCheck;
+    use paidy_submission::domain::repository::{
+        ItemRepository, MockItemRepository, RepositoryError,
+    };
+    use paidy_submission::domain::station::Station;
+    use paidy_submission::infrastructure::health_check::CircuitBreakerHealthCheck;
+    use paidy_submission::infrastructure::metrics::PrometheusMetrics;
+    use paidy_submission::infrastructure::resilient_repository::{
+        CircuitBreaker, CircuitState, ResilienceConfiguration, ResilientItemRepository,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn config(retry_attempts: u32, failure_threshold: u32) -> ResilienceConfiguration {
+        ResilienceConfiguration {
+            retry_attempts,
+            retry_backoff: Duration::from_millis(1),
+            retry_max_backoff: Duration::from_millis(5),
+            failure_threshold,
+            open_duration: Duration::from_millis(50),
+        }
+    }
+
+    fn unavailable() -> RepositoryError {
+        RepositoryError::Unavailable("connection refused".to_string())
+    }
+
+    fn resilient(
+        inner: MockItemRepository,
+        config: ResilienceConfiguration,
+    ) -> (ResilientItemRepository, Arc<CircuitBreaker>) {
+        let breaker = Arc::new(CircuitBreaker::new(
+            config.failure_threshold,
+            config.open_duration,
+        ));
+        let repository = ResilientItemRepository::new(Arc::new(inner), breaker.clone(), config);
+        (repository, breaker)
+    }
+
+    #[tokio::test]
+    async fn should_retry_transient_read_errors() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut inner = MockItemRepository::new();
+        inner.expect_find_item().times(3).returning(move |_, _| {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(unavailable()),
+                _ => Ok(None),
+            }
+        });
+
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let (repository, breaker) = resilient(inner, config(2, 5));
+        let repository = repository.with_metrics(metrics.clone());
+
+        let result = repository.find_item(&1, &Uuid::now_v7()).await;
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(metrics
+            .render()
+            .contains("db_retries_total{operation=\"find_item\"} 2"));
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_configured_attempts() {
+        let mut inner = MockItemRepository::new();
+        inner
+            .expect_find_items_by_station()
+            .times(2)
+            .returning(|_| Err(unavailable()));
+
+        let (repository, _) = resilient(inner, config(1, 5));
+
+        let result = repository.find_items_by_station(&Station::Bar).await;
+
+        assert_eq!(result, Err(unavailable()));
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_writes() {
+        let mut inner = MockItemRepository::new();
+        inner
+            .expect_delete_item()
+            .once()
+            .returning(|_, _| Err(unavailable()));
+
+        let (repository, _) = resilient(inner, config(3, 5));
+
+        let result = repository.delete_item(&1, &Uuid::now_v7()).await;
+
+        assert_eq!(result, Err(unavailable()));
+    }
+
+    #[tokio::test]
+    async fn should_fail_fast_while_breaker_is_open() {
+        let mut inner = MockItemRepository::new();
+        inner
+            .expect_find_item()
+            .times(3)
+            .returning(|_, _| Err(unavailable()));
+
+        let (repository, breaker) = resilient(inner, config(0, 3));
+        for _ in 0..3 {
+            let _ = repository.find_item(&1, &Uuid::now_v7()).await;
+        }
+
+        let result = repository.find_item(&1, &Uuid::now_v7()).await;
+
+        assert_eq!(
+            result,
+            Err(RepositoryError::Unavailable(
+                "Circuit breaker is open".to_string()
+            ))
+        );
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(
+            CircuitBreakerHealthCheck::new(breaker).check().await,
+            Err("Circuit breaker is open".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_close_breaker_after_successful_trial() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut inner = MockItemRepository::new();
+        inner.expect_find_item().times(2).returning(move |_, _| {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(unavailable()),
+                _ => Ok(None),
+            }
+        });
+
+        let (repository, breaker) = resilient(inner, config(0, 1));
+        let _ = repository.find_item(&1, &Uuid::now_v7()).await;
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let result = repository.find_item(&1, &Uuid::now_v7()).await;
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(
+            CircuitBreakerHealthCheck::new(breaker).check().await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_ignore_non_transient_errors() {
+        let mut inner = MockItemRepository::new();
+        inner.expect_find_item().once().returning(|_, _| {
+            Err(RepositoryError::InternalRepositoryError(
+                "syntax error".to_string(),
+            ))
+        });
+
+        let (repository, breaker) = resilient(inner, config(3, 1));
+
+        let result = repository.find_item(&1, &Uuid::now_v7()).await;
+
+        assert!(matches!(
+            result,
+            Err(RepositoryError::InternalRepositoryError(_))
+        ));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
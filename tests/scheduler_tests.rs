@@ -0,0 +1,52 @@
+mod scheduler_tests {
+    use paidy_submission::server::scheduler::Scheduler;
+    use paidy_submission::server::shutdown::Shutdown;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn should_stop_job_once_shutdown_is_triggered() {
+        let shutdown = Shutdown::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let job = Scheduler::spawn(
+            "test",
+            Duration::from_millis(10),
+            shutdown.clone(),
+            move || {
+                let counter = counter.clone();
+                async move { Ok(counter.fetch_add(1, Ordering::SeqCst)) }
+            },
+        );
+        tokio::time::sleep(Duration::from_millis(35)).await;
+        shutdown.trigger();
+
+        let stopped = tokio::time::timeout(Duration::from_secs(1), job).await;
+        let runs_at_shutdown = runs.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert!(matches!(stopped, Ok(Ok(()))));
+        assert!(runs_at_shutdown > 0);
+        assert_eq!(runs.load(Ordering::SeqCst), runs_at_shutdown);
+    }
+
+    #[tokio::test]
+    async fn should_not_start_job_after_shutdown() {
+        let shutdown = Shutdown::new();
+        shutdown.trigger();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let job = Scheduler::spawn("test", Duration::from_secs(60), shutdown, move || {
+            let counter = counter.clone();
+            async move { Ok(counter.fetch_add(1, Ordering::SeqCst)) }
+        });
+
+        let stopped = tokio::time::timeout(Duration::from_secs(1), job).await;
+
+        assert!(matches!(stopped, Ok(Ok(()))));
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,135 @@
+mod telemetry_tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use chrono::Utc;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::{Key, Value};
+    use opentelemetry_sdk::export::trace::SpanData;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+    use opentelemetry_sdk::trace::TracerProvider;
+    use paidy_submission::application::item_service::ItemServiceImpl;
+    use paidy_submission::domain::item::{Item, OverduePolicy};
+    use paidy_submission::domain::item_factory::MockItemFactory;
+    use paidy_submission::domain::repository::MockItemRepository;
+    use paidy_submission::domain::station::Station;
+    use paidy_submission::server::middleware::TraceMiddleware;
+    use paidy_submission::server::telemetry::Telemetry;
+    use paidy_submission::web::item_endpoint::ItemRouter;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use testcontainers::core::{ContainerPort, WaitFor};
+    use testcontainers::runners::AsyncRunner;
+    use testcontainers::GenericImage;
+    use tower::ServiceExt;
+    use tracing_subscriber::layer::SubscriberExt;
+    use uuid::Uuid;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+    fn attribute(span: &SpanData, key: &'static str) -> Option<Value> {
+        span.attributes
+            .iter()
+            .find(|attribute| attribute.key == Key::from_static_str(key))
+            .map(|attribute| attribute.value.clone())
+    }
+
+    async fn get_item(item: &Item) -> Vec<SpanData> {
+        let exporter = InMemorySpanExporter::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mut repository = MockItemRepository::new();
+        repository
+            .expect_find_item()
+            .return_const(Ok(Some(item.clone())));
+        let service = ItemServiceImpl::new(Arc::new(repository), Arc::new(MockItemFactory::new()));
+        let router = ItemRouter::create(Arc::new(service), OverduePolicy::default())
+            .layer(TraceMiddleware::create());
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/tables/{}/items/{}", item.table_id, item.id))
+                    .header(
+                        "traceparent",
+                        format!("00-{}-{}-01", TRACE_ID, PARENT_SPAN_ID),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        // The HTTP span ends once the response body is dropped.
+        drop(response);
+
+        exporter.get_finished_spans().expect("Failed to get spans")
+    }
+
+    #[tokio::test]
+    async fn should_continue_incoming_trace_and_nest_service_span() {
+        let item = Item::try_new(
+            Uuid::now_v7(),
+            7,
+            "Pierogi".to_string(),
+            Utc::now(),
+            Station::Grill,
+        )
+        .expect("Failed to create item");
+
+        let spans = get_item(&item).await;
+        let http = spans
+            .iter()
+            .find(|span| span.name == "GET /tables/:table_id/items/:item_id")
+            .expect("HTTP span not exported");
+        let service = spans
+            .iter()
+            .find(|span| span.name == "get_item")
+            .expect("Service span not exported");
+
+        assert_eq!(http.span_context.trace_id().to_string(), TRACE_ID);
+        assert_eq!(http.parent_span_id.to_string(), PARENT_SPAN_ID);
+        assert_eq!(service.span_context.trace_id().to_string(), TRACE_ID);
+        assert_eq!(service.parent_span_id, http.span_context.span_id());
+        assert_eq!(attribute(service, "table_id"), Some(Value::I64(7)));
+        assert_eq!(
+            attribute(service, "item_id"),
+            Some(Value::from(item.id.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_export_spans_to_collector() {
+        let container = GenericImage::new("otel/opentelemetry-collector", "0.115.1")
+            .with_exposed_port(ContainerPort::Tcp(4318))
+            .with_wait_for(WaitFor::message_on_stderr("Everything is ready"))
+            .start()
+            .await
+            .expect("Failed to create collector container");
+        let port = container
+            .get_host_port_ipv4(4318)
+            .await
+            .expect("Failed to get port");
+
+        let provider = Telemetry::provider(&format!("http://localhost:{}", port), "test".into())
+            .expect("Failed to create provider");
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("exported_span").in_scope(|| {});
+        });
+        provider.force_flush();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let logs = container
+            .stderr_to_vec()
+            .await
+            .expect("Failed to read logs");
+
+        assert!(String::from_utf8_lossy(&logs).contains("\"spans\": 1"));
+    }
+}